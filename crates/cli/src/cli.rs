@@ -1,17 +1,63 @@
 use crate::logging;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{CommandFactory, Parser};
 use clap_complete;
 use novalyn_core::{
-    ecow::EcoVec,
-    github,
-    pipeline::{ExitCode, ReleaseOptions},
-    semver, tokio,
+    changelog, config,
+    ecow::{EcoString, EcoVec},
+    git, github,
+    pipeline::{ExitCode, ReleaseOptions, ReleaseOutcome},
+    render, semver, utils,
 };
+use tracing::warn;
 
-pub use crate::cli_def::{Cli, Commands, Completions};
+pub use crate::cli_def::{ChangelogFormat, Cli, Commands, Completions, OutputFormat};
 
-pub async fn run() -> Result<ExitCode> {
+/// JSON summary printed by `generate --format json`.
+///
+/// Field names are stable; with `--workspace` an array of these is printed,
+/// each carrying its `member` path.
+#[derive(Debug, serde::Serialize)]
+pub struct GenerateOutput<'a> {
+    /// Workspace member directory relative to the root (workspace runs only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<String>,
+    /// The new version
+    pub version: String,
+    /// The version released before, "0.0.0" when there was none
+    pub previous_version: Option<String>,
+    /// Inferred bump: "major", "minor", "patch" or "none" (explicit version)
+    pub bump: novalyn_core::parse::BumpKind,
+    /// Commits included in the release block
+    pub commit_count: usize,
+    /// Commits left out because they are not conventional (strict mode only)
+    pub non_conventional: usize,
+    /// Whether CHANGELOG.md was updated
+    pub wrote: bool,
+    /// Rendered markdown release block
+    pub markdown: &'a str,
+    /// Configuration warnings, also logged to stderr
+    pub warnings: &'a [EcoString],
+}
+
+impl<'a> GenerateOutput<'a> {
+    fn new(outcome: &'a ReleaseOutcome, member: Option<String>) -> Self {
+        Self {
+            member,
+            version: outcome.version.to_string(),
+            previous_version: outcome.previous.as_ref().map(ToString::to_string),
+            bump: outcome.bump,
+            commit_count: outcome.commit_count,
+            non_conventional: outcome.non_conventional,
+            wrote: outcome.wrote,
+            markdown: &outcome.block,
+            warnings: &outcome.warnings,
+        }
+    }
+}
+
+/// Run the CLI and return the process exit code.
+pub async fn run() -> Result<i32> {
     let cli = Cli::parse();
     logging::init(cli.verbose as usize);
     let cwd = cli
@@ -19,27 +65,56 @@ pub async fn run() -> Result<ExitCode> {
         .as_ref()
         .map(std::path::PathBuf::from)
         .unwrap_or(std::env::current_dir()?);
+    let no_metadata = cli.no_metadata;
+    if cli.dump_config_sources {
+        eprint!(
+            "{}",
+            load_config(&cwd, no_metadata).await?.describe_sources()
+        );
+    }
+    let no_change_exit_code = cli.no_change_exit_code;
+    let treat_no_change_as_success = cli.treat_no_change_as_success;
     let exit = match cli.command {
         Commands::Completions(completions) => {
+            let Some(shell) = completions.shell.or_else(crate::shells::Shell::from_env) else {
+                anyhow::bail!("could not detect the current shell; pass one explicitly");
+            };
             let mut cmd = Cli::command();
-            clap_complete::generate(
-                completions.shell,
-                &mut cmd,
-                "novalyn",
-                &mut std::io::stdout(),
-            );
+            clap_complete::generate(shell, &mut cmd, "novalyn", &mut std::io::stdout());
+            ExitCode::Success
+        }
+        Commands::Init { force, stdout } => {
+            let starter = config::starter_config();
+            if stdout {
+                print!("{starter}");
+            } else {
+                let path = cwd.join("novalyn.toml");
+                if !force && path.exists() {
+                    anyhow::bail!(
+                        "{} already exists (use --force to overwrite)",
+                        path.display()
+                    );
+                }
+                std::fs::write(&path, starter)?;
+                println!("Wrote {}", path.display());
+            }
             ExitCode::Success
         }
         Commands::Show {
             from,
             to,
+            limit,
             new_version,
+            no_manifest_version,
+            version_file,
+            github_output,
         } => {
             let parsed_new = new_version.and_then(|s| semver::Version::parse(&s).ok());
             let outcome = novalyn_core::pipeline::run_release_async(ReleaseOptions {
-                cwd,
+                cwd: cwd.clone(),
                 from: from.map(|s| s.into()),
                 to: to.map(|s| s.into()),
+                limit,
                 dry_run: true,
                 new_version: parsed_new,
                 no_authors: true,
@@ -50,25 +125,53 @@ pub async fn run() -> Result<ExitCode> {
                 yes: true, // Show command doesn't need confirmation
                 github_alias: false,
                 github_token: None,
+                exclude_paths: EcoVec::new(),
+                strict_conventional: false,
+                report: None,
+                no_metadata,
+                no_manifest_version,
+                ..Default::default()
             })
             .await?;
             println!("{}", outcome.version);
+            export_version(&cwd, &outcome, version_file.as_deref(), github_output)?;
             ExitCode::Success
         }
         Commands::Generate {
             write,
             output,
+            insert_after,
             from,
             to,
+            limit,
+            base,
             new_version,
+            no_manifest_version,
             no_authors,
             exclude_author,
+            path,
+            exclude_path,
+            strict_conventional,
+            interactive,
+            save_keyword_types,
+            report,
+            no_compare,
+            no_emoji,
+            pre_release_identifier_format,
+            prerelease,
+            workspace,
+            format,
+            output_format,
+            version_file,
+            github_output,
             hide_author_email,
             clean,
+            force,
             sign,
             yes,
             no_github_alias,
             github_token,
+            cache_handles,
         } => {
             // Read GitHub token from env if not provided
             let github_token = github_token.or_else(|| {
@@ -77,59 +180,128 @@ pub async fn run() -> Result<ExitCode> {
                     .or_else(|| std::env::var("GH_TOKEN").ok())
             });
 
+            // "-" prints the block instead of writing a file
+            let to_stdout = output.as_deref() == Some("-");
+            let insert_after = insert_after
+                .map(|v| {
+                    semver::Version::parse(v.trim_start_matches('v'))
+                        .with_context(|| format!("invalid --insert-after version {v:?}"))
+                })
+                .transpose()?;
+            let write = (write || output.is_some() || insert_after.is_some()) && !to_stdout;
             let parsed_new = new_version.and_then(|s| semver::Version::parse(&s).ok());
-            let outcome = novalyn_core::pipeline::run_release_async(ReleaseOptions {
+            let opts = ReleaseOptions {
                 cwd: cwd.clone(),
                 from: from.map(|s| s.into()),
                 to: to.map(|s| s.into()),
+                limit,
                 dry_run: !write,
+                output: output.filter(|_| !to_stdout).map(Into::into),
+                insert_after,
                 new_version: parsed_new,
                 no_authors,
                 exclude_authors: exclude_author.into_iter().map(|s| s.into()).collect(),
                 hide_author_email,
                 clean,
+                force,
                 sign,
                 yes,
                 github_alias: !no_github_alias,
                 github_token: github_token.map(|s| s.into()),
-            })
-            .await?;
-            if let Some(path) = output {
-                std::fs::write(&path, outcome.version.to_string())?;
-            }
-            println!(
-                "Generated v{} ({} commits){}",
-                outcome.version,
-                outcome.commit_count,
-                if write {
-                    if outcome.wrote {
-                        " and updated CHANGELOG.md"
-                    } else {
-                        " (no change)"
+                handle_cache: cache_handles,
+                exclude_paths: exclude_path.into_iter().map(Into::into).collect(),
+                paths: path.into_iter().map(Into::into).collect(),
+                strict_conventional,
+                interactive,
+                save_keyword_types,
+                report: report.map(Into::into),
+                no_compare,
+                no_emoji,
+                prerelease_format: pre_release_identifier_format.map(Into::into),
+                prerelease: prerelease.map(Into::into),
+                base: base.map(Into::into),
+                // Start after the last release on this branch, not the repo-wide last tag
+                default_previous_tag_strategy: Some(config::PreviousTagStrategy::SemverReachable),
+                no_metadata,
+                no_manifest_version,
+                block_format: match output_format {
+                    ChangelogFormat::Markdown => render::BlockFormat::Markdown,
+                    ChangelogFormat::Rst => render::BlockFormat::Rst,
+                },
+                ..Default::default()
+            };
+            if workspace {
+                let outcomes = novalyn_core::pipeline::run_workspace_release_async(opts).await?;
+                if format == OutputFormat::Json {
+                    let json: Vec<_> = outcomes
+                        .iter()
+                        .map(|(member, outcome)| {
+                            let name = member.strip_prefix(&cwd).unwrap_or(member);
+                            GenerateOutput::new(outcome, Some(name.display().to_string()))
+                        })
+                        .collect();
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                } else {
+                    for (member, outcome) in &outcomes {
+                        let name = member.strip_prefix(&cwd).unwrap_or(member);
+                        println!("{}: {}", name.display(), generated_summary(outcome, write));
                     }
+                }
+                if write && !outcomes.iter().any(|(_, o)| o.wrote) {
+                    ExitCode::NoChange
                 } else {
-                    ""
+                    ExitCode::Success
                 }
-            );
-            if !outcome.wrote && write {
-                ExitCode::NoChange
             } else {
-                ExitCode::Success
+                let outcome = novalyn_core::pipeline::run_release_async(opts).await?;
+                export_version(&cwd, &outcome, version_file.as_deref(), github_output)?;
+                if to_stdout {
+                    print!("{}", outcome.block);
+                } else if format == OutputFormat::Json {
+                    let json = GenerateOutput::new(&outcome, None);
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                } else {
+                    println!("{}", generated_summary(&outcome, write));
+                }
+                if !outcome.wrote && write {
+                    ExitCode::NoChange
+                } else {
+                    ExitCode::Success
+                }
             }
         }
         Commands::Release {
             dry_run,
+            print_notes_only,
+            output,
             from,
             to,
+            limit,
             new_version,
+            no_manifest_version,
             no_authors,
             exclude_author,
+            path,
+            exclude_path,
+            strict_conventional,
+            interactive,
+            save_keyword_types,
+            report,
+            no_compare,
+            no_emoji,
+            pre_release_identifier_format,
+            prerelease,
+            update_lockfile,
+            version_file,
+            github_output,
             hide_author_email,
             clean,
+            force,
             sign,
             yes,
             no_github_alias,
             github_token,
+            cache_handles,
         } => {
             // Read GitHub token from env if not provided
             let github_token = github_token.or_else(|| {
@@ -138,58 +310,123 @@ pub async fn run() -> Result<ExitCode> {
                     .or_else(|| std::env::var("GH_TOKEN").ok())
             });
 
+            let print_notes_only = print_notes_only || output.as_deref() == Some("-");
             let parsed_new = new_version.and_then(|s| semver::Version::parse(&s).ok());
             let outcome = novalyn_core::pipeline::run_release_async(ReleaseOptions {
                 cwd: cwd.clone(),
                 from: from.map(|s| s.into()),
                 to: to.map(|s| s.into()),
-                dry_run,
+                limit,
+                dry_run: dry_run || print_notes_only,
+                output: output.filter(|_| !print_notes_only).map(Into::into),
                 new_version: parsed_new,
                 no_authors,
                 exclude_authors: exclude_author.into_iter().map(|s| s.into()).collect(),
                 hide_author_email,
                 clean,
+                force,
                 sign,
-                yes,
+                yes: yes || print_notes_only,
                 github_alias: !no_github_alias,
                 github_token: github_token.map(|s| s.into()),
+                handle_cache: cache_handles,
+                exclude_paths: exclude_path.into_iter().map(Into::into).collect(),
+                paths: path.into_iter().map(Into::into).collect(),
+                strict_conventional,
+                interactive,
+                save_keyword_types,
+                report: report.map(Into::into),
+                no_compare,
+                no_emoji,
+                prerelease_format: pre_release_identifier_format.map(Into::into),
+                prerelease: prerelease.map(Into::into),
+                update_lockfile,
+                no_metadata,
+                no_manifest_version,
+                run_hooks: !print_notes_only,
+                ..Default::default()
             })
             .await?;
-            if outcome.wrote {
+            export_version(&cwd, &outcome, version_file.as_deref(), github_output)?;
+            for hook in &outcome.skipped_hooks {
+                println!("Would run hook: {hook}");
+            }
+            if print_notes_only {
+                print!("{}", outcome.block);
+                ExitCode::Success
+            } else if outcome.wrote {
                 println!("Released v{}", outcome.version);
+                if outcome.non_conventional > 0 {
+                    println!(
+                        "Skipped {} non-conventional commits",
+                        outcome.non_conventional
+                    );
+                }
                 ExitCode::Success
             } else {
-                println!("No change for v{}", outcome.version);
+                let cfg = load_config(&cwd, no_metadata).await?;
+                println!("{}", cfg.no_change_message_for(&outcome.version));
                 ExitCode::NoChange
             }
         }
-        Commands::Github { tag, body_path } => {
-            // Minimal body read
-            let body = if let Some(path) = body_path {
-                std::fs::read_to_string(path)?
-            } else {
-                String::new()
-            };
+        Commands::Github {
+            tag,
+            body_path,
+            output,
+            dry_run,
+            draft,
+            prerelease,
+            no_prerelease,
+        } => {
             // attempt repo detection via config layer
             let cfg = novalyn_core::config::load_config(novalyn_core::config::LoadOptions {
                 cwd: &cwd,
-                cli_overrides: None,
+                cli_overrides: no_metadata.then(config::RawConfig::without_metadata),
             })?;
-            if let Some(repo) = cfg.repo {
-                let rt = tokio::runtime::Runtime::new()?;
-                let info = rt.block_on(async move {
-                    github::sync_release(&repo, cfg.github_token.as_deref(), &tag, &body, None)
-                        .await
-                });
+            let body = if let Some(path) = body_path {
+                std::fs::read_to_string(path)?
+            } else {
+                changelog_notes(&cfg, &tag, output.as_deref())
+            };
+            if let Some(repo) = &cfg.repo {
+                let name = release_name(&cfg, &tag);
+                // Detect prereleases through the configured tag format unless overridden
+                let prerelease = match (prerelease, no_prerelease) {
+                    (true, _) => Some(true),
+                    (_, true) => Some(false),
+                    _ => cfg.parse_tag(&tag).map(|v| !v.pre.is_empty()),
+                };
+                let payload = github::ReleasePayload {
+                    tag: &tag,
+                    name: &name,
+                    body: &body,
+                    draft,
+                    prerelease,
+                };
+                let info = if dry_run {
+                    println!("{}", payload.request_json()?);
+                    None
+                } else {
+                    Some(
+                        github::sync_release_with(
+                            repo,
+                            cfg.github_token.as_deref(),
+                            &payload,
+                            Some(&cfg.github_api_url),
+                        )
+                        .await,
+                    )
+                };
                 match info {
-                    Ok(r) => {
+                    None => ExitCode::Success,
+                    Some(Ok(r)) => {
                         println!(
                             "GitHub release {}: {} (created={}, updated={}, skipped={})",
                             r.tag, r.url, r.created, r.updated, r.skipped
                         );
                         ExitCode::Success
                     }
-                    Err(e) => {
+                    Some(Err(e)) => {
                         eprintln!("github sync error: {e}");
                         ExitCode::NoChange
                     }
@@ -200,8 +437,133 @@ pub async fn run() -> Result<ExitCode> {
             }
         }
     };
-    Ok(exit)
+    let code = match exit {
+        ExitCode::Success => 0,
+        ExitCode::NoChange if treat_no_change_as_success => 0,
+        ExitCode::NoChange => match no_change_exit_code {
+            Some(code) => code,
+            None => load_config(&cwd, no_metadata).await?.no_change_exit_code,
+        },
+    };
+    Ok(code)
+}
+
+/// Load the layered config for `cwd`, optionally without Cargo.toml metadata.
+async fn load_config(cwd: &std::path::Path, no_metadata: bool) -> Result<config::ResolvedConfig> {
+    config::load_config_async(config::LoadOptions {
+        cwd,
+        cli_overrides: no_metadata.then(config::RawConfig::without_metadata),
+    })
+    .await
+}
+
+/// GitHub release title for `tag`.
+///
+/// The previous version is the highest tag reachable from the tagged commit's
+/// parent. Tags that don't parse as a version are used as the title verbatim.
+fn release_name(cfg: &config::ResolvedConfig, tag: &str) -> novalyn_core::ecow::EcoString {
+    let Some(version) = cfg.parse_tag(tag) else {
+        return tag.into();
+    };
+    let previous = git::detect_repo(&cfg.cwd)
+        .ok()
+        .and_then(|repo| {
+            git::max_reachable_semver_tag_with(&repo, &format!("{tag}^"), |t| cfg.parse_tag(t))
+                .ok()
+                .flatten()
+        })
+        .and_then(|t| cfg.parse_tag(&t));
+    cfg.release_name(tag, &version, previous.as_ref(), &utils::today())
 }
+
+/// Release notes for `tag` taken from the changelog the release command
+/// writes: `output` (default CHANGELOG.md) in the config's cwd.
+///
+/// Falls back to an empty body (with a warning) when there is no block.
+fn changelog_notes(cfg: &config::ResolvedConfig, tag: &str, output: Option<&str>) -> String {
+    let path = cfg.cwd.join(output.unwrap_or("CHANGELOG.md"));
+    let notes = cfg.parse_tag(tag).and_then(|version| {
+        let existing = std::fs::read_to_string(&path).ok()?;
+        changelog::release_notes(&existing, &version)
+    });
+    match notes {
+        Some(notes) => notes.into(),
+        None => {
+            warn!(
+                "no notes for {tag} in {}; using an empty body",
+                path.display()
+            );
+            String::new()
+        }
+    }
+}
+
+/// Export the computed version for CI.
+///
+/// `version_file` (relative to `cwd`) receives the bare version;
+/// `github_output` appends `version=` and `bump=` lines to `$GITHUB_OUTPUT`.
+fn export_version(
+    cwd: &std::path::Path,
+    outcome: &ReleaseOutcome,
+    version_file: Option<&str>,
+    github_output: bool,
+) -> Result<()> {
+    if let Some(path) = version_file {
+        let path = cwd.join(path);
+        std::fs::write(&path, format!("{}\n", outcome.version))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+    if github_output {
+        use std::io::Write;
+        let Some(path) = std::env::var_os("GITHUB_OUTPUT") else {
+            anyhow::bail!("--github-output requires the GITHUB_OUTPUT environment variable");
+        };
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("failed to open {}", path.to_string_lossy()))?;
+        write!(
+            file,
+            "version={}\nbump={}\n",
+            outcome.version,
+            outcome.bump.as_str()
+        )?;
+    }
+    Ok(())
+}
+
+/// One-line summary printed by `generate`.
+fn generated_summary(outcome: &ReleaseOutcome, write: bool) -> String {
+    format!(
+        "Generated v{} ({} commits{}){}",
+        outcome.version,
+        outcome.commit_count,
+        skipped_note(outcome.non_conventional),
+        match (write, outcome.wrote) {
+            (true, true) => format!(
+                " and updated {}",
+                outcome
+                    .changelog_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+            ),
+            (true, false) => " (no change)".into(),
+            (false, _) => String::new(),
+        }
+    )
+}
+
+/// Summary suffix for commits skipped by `--strict-conventional`.
+fn skipped_note(count: usize) -> String {
+    if count == 0 {
+        String::new()
+    } else {
+        format!(", {count} non-conventional skipped")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
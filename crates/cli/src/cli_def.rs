@@ -15,12 +15,33 @@ pub struct Cli {
     /// Increase verbosity (-v, -vv, -vvv)
     #[arg(short = 'v', long, action = ArgAction::Count)]
     pub verbose: u8,
+    /// Print each loaded config source and the keys it contributed (to stderr)
+    #[arg(long, global = true)]
+    pub dump_config_sources: bool,
+    /// Ignore [package.metadata.novalyn] in Cargo.toml
+    #[arg(long, global = true)]
+    pub no_metadata: bool,
+    /// Exit code used when a run produces no change (overrides config, default 3)
+    #[arg(long, global = true, value_name = "CODE")]
+    pub no_change_exit_code: Option<i32>,
+    /// Exit with 0 when a run produces no change
+    #[arg(long, global = true, conflicts_with = "no_change_exit_code")]
+    pub treat_no_change_as_success: bool,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Generate shell completions
     Completions(Completions),
+    /// Write a starter novalyn.toml with the default commit types.
+    Init {
+        /// Overwrite an existing novalyn.toml
+        #[arg(long)]
+        force: bool,
+        /// Print the config to stdout instead of writing novalyn.toml
+        #[arg(long, conflicts_with = "force")]
+        stdout: bool,
+    },
     /// Show the next inferred version based on commit history and semver rules.
     Show {
         /// From tag version range
@@ -29,38 +50,119 @@ pub enum Commands {
         /// To tag version range
         #[arg(long, short)]
         to: Option<String>,
+        /// Only process the most recent N commits of the range
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
         /// Override the inferred next version (e.g. "1.2.3")
         #[arg(long, short, value_name = "SEMVER")]
         new_version: Option<String>,
+        /// Start from 0.0.0 instead of the Cargo.toml version when no release tag exists
+        #[arg(long)]
+        no_manifest_version: bool,
+        /// Write the bare new version (no "v") to this file, for CI
+        #[arg(long, value_name = "PATH")]
+        version_file: Option<String>,
+        /// Append `version=` and `bump=` lines to the file named by $GITHUB_OUTPUT
+        #[arg(long)]
+        github_output: bool,
     },
     /// Generate a changelog release block for the specified commit range.
     Generate {
         /// Write the changelog to CHANGELOG.md (default: print to stdout)
         #[arg(long, short)]
         write: bool,
-        /// Output path for changelog file (implies --write if path is CHANGELOG.md)
+        /// Changelog file to update instead of CHANGELOG.md (implies --write; "-" prints the block to stdout)
         #[arg(long, short, value_name = "PATH")]
         output: Option<String>,
+        /// Backfill the block directly above this existing release, e.g. "1.1.0" (implies --write)
+        #[arg(long, value_name = "VERSION", conflicts_with = "workspace")]
+        insert_after: Option<String>,
         /// From tag version range
         #[arg(long, short)]
         from: Option<String>,
         /// To tag version range
         #[arg(long, short)]
         to: Option<String>,
+        /// Only process the most recent N commits of the range
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+        /// Only include commits since the merge-base with this branch
+        #[arg(long, value_name = "BRANCH", conflicts_with = "from")]
+        base: Option<String>,
         /// Override the inferred next version (e.g. "1.2.3")
         #[arg(long, value_name = "SEMVER", short)]
         new_version: Option<String>,
+        /// Start from 0.0.0 instead of the Cargo.toml version when no release tag exists
+        #[arg(long)]
+        no_manifest_version: bool,
         /// Exclude contributors section from changelog
         #[arg(long, short = 'N')]
         no_authors: bool,
         /// Exclude specific authors by name or email (repeatable)
         #[arg(long, short, value_name = "NAME_OR_EMAIL")]
         exclude_author: Vec<String>,
+        /// Only include commits touching files under this path (repeatable)
+        #[arg(long, value_name = "PATH")]
+        path: Vec<String>,
+        /// Drop commits that only touch files under this path (repeatable)
+        #[arg(long, value_name = "PATH")]
+        exclude_path: Vec<String>,
+        /// Leave out commits that don't follow the conventional commit format
+        #[arg(long)]
+        strict_conventional: bool,
+        /// Prompt for a type for each non-conventional commit (needs a terminal; skipped with --yes)
+        #[arg(long, conflicts_with_all = ["strict_conventional", "workspace"])]
+        interactive: bool,
+        /// Save --interactive choices to [keyword_types] in novalyn.toml
+        #[arg(long, requires = "interactive")]
+        save_keyword_types: bool,
+        /// Write a JSON report of commit classification and version impact
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
+        /// Omit the compare link under the release heading
+        #[arg(long)]
+        no_compare: bool,
+        /// Leave type emoji out of section headings and entries
+        #[arg(long)]
+        no_emoji: bool,
+        /// Produce a prerelease with this identifier format (e.g. "rc.{{n}}", "nightly.{{date}}.{{n}}")
+        #[arg(long, value_name = "FORMAT")]
+        pre_release_identifier_format: Option<String>,
+        /// Produce a prerelease on this channel (e.g. "rc" gives rc.1, rc.2, ...)
+        #[arg(
+            long,
+            value_name = "CHANNEL",
+            conflicts_with = "pre_release_identifier_format"
+        )]
+        prerelease: Option<String>,
+        /// Generate a changelog for every workspace member from the root Cargo.toml
+        #[arg(long, conflicts_with = "output")]
+        workspace: bool,
+        /// Summary output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Markup of the release block; rst can only be printed (--output -)
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = ChangelogFormat::Markdown,
+            conflicts_with_all = ["write", "insert_after", "workspace"]
+        )]
+        output_format: ChangelogFormat,
+        /// Write the bare new version (no "v") to this file, for CI
+        #[arg(long, value_name = "PATH", conflicts_with = "workspace")]
+        version_file: Option<String>,
+        /// Append `version=` and `bump=` lines to the file named by $GITHUB_OUTPUT
+        #[arg(long, conflicts_with = "workspace")]
+        github_output: bool,
         /// Hide authors emails
         #[arg(long, short = 'E')]
         hide_author_email: bool,
         #[arg(long, short)]
         clean: bool,
+        /// Write CHANGELOG.md even if it has unresolved merge conflict markers
+        #[arg(long)]
+        force: bool,
         /// Sign release
         #[arg(long, short)]
         sign: bool,
@@ -73,32 +175,93 @@ pub enum Commands {
         /// GitHub token for API access (reads from GITHUB_TOKEN or GH_TOKEN env vars)
         #[arg(long, short)]
         github_token: Option<String>,
+        /// Cache email to @handle lookups in ~/.cache/novalyn/github-handles.json
+        #[arg(long, conflicts_with = "no_github_alias")]
+        cache_handles: bool,
     },
     /// Run a full release: bump version, generate changelog, create git tag, and optionally sign/tag.
     Release {
         /// Simulate the release process without making changes (preview only)
         #[arg(long, short)]
         dry_run: bool,
+        /// Print only the rendered release notes to stdout (no writes, tags or prompts)
+        #[arg(long, conflicts_with = "dry_run")]
+        print_notes_only: bool,
+        /// Changelog file to update instead of CHANGELOG.md ("-" is the same as --print-notes-only)
+        #[arg(long, short, value_name = "PATH")]
+        output: Option<String>,
         /// From tag version range
         #[arg(long, short)]
         from: Option<String>,
         /// To tag version range
         #[arg(long, short)]
         to: Option<String>,
+        /// Only process the most recent N commits of the range
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
         /// Override the inferred next version (e.g. "1.2.3")
         #[arg(long, value_name = "SEMVER")]
         new_version: Option<String>,
+        /// Start from 0.0.0 instead of the Cargo.toml version when no release tag exists
+        #[arg(long)]
+        no_manifest_version: bool,
         /// Exclude contributors section from changelog
         #[arg(long, short)]
         no_authors: bool,
         /// Exclude specific authors by name or email (repeatable)
         #[arg(long, value_name = "NAME_OR_EMAIL")]
         exclude_author: Vec<String>,
+        /// Only include commits touching files under this path (repeatable)
+        #[arg(long, value_name = "PATH")]
+        path: Vec<String>,
+        /// Drop commits that only touch files under this path (repeatable)
+        #[arg(long, value_name = "PATH")]
+        exclude_path: Vec<String>,
+        /// Leave out commits that don't follow the conventional commit format
+        #[arg(long)]
+        strict_conventional: bool,
+        /// Prompt for a type for each non-conventional commit (needs a terminal; skipped with --yes)
+        #[arg(long, conflicts_with = "strict_conventional")]
+        interactive: bool,
+        /// Save --interactive choices to [keyword_types] in novalyn.toml
+        #[arg(long, requires = "interactive")]
+        save_keyword_types: bool,
+        /// Write a JSON report of commit classification and version impact
+        #[arg(long, value_name = "PATH")]
+        report: Option<String>,
+        /// Omit the compare link under the release heading
+        #[arg(long)]
+        no_compare: bool,
+        /// Leave type emoji out of section headings and entries
+        #[arg(long)]
+        no_emoji: bool,
+        /// Produce a prerelease with this identifier format (e.g. "rc.{{n}}", "nightly.{{date}}.{{n}}")
+        #[arg(long, value_name = "FORMAT")]
+        pre_release_identifier_format: Option<String>,
+        /// Produce a prerelease on this channel (e.g. "rc" gives rc.1, rc.2, ...)
+        #[arg(
+            long,
+            value_name = "CHANNEL",
+            conflicts_with = "pre_release_identifier_format"
+        )]
+        prerelease: Option<String>,
+        /// Bump the version in Cargo.toml and the package's Cargo.lock entry
+        #[arg(long)]
+        update_lockfile: bool,
+        /// Write the bare new version (no "v") to this file, for CI
+        #[arg(long, value_name = "PATH")]
+        version_file: Option<String>,
+        /// Append `version=` and `bump=` lines to the file named by $GITHUB_OUTPUT
+        #[arg(long)]
+        github_output: bool,
         /// Hide authors emails
         #[arg(long, short = 'E')]
         hide_author_email: bool,
         #[arg(long, short)]
         clean: bool,
+        /// Write CHANGELOG.md even if it has unresolved merge conflict markers
+        #[arg(long)]
+        force: bool,
         /// Sign release
         #[arg(long, short)]
         sign: bool,
@@ -111,21 +274,60 @@ pub enum Commands {
         /// GitHub token for API access (reads from GITHUB_TOKEN or GH_TOKEN env vars)
         #[arg(long, short)]
         github_token: Option<String>,
+        /// Cache email to @handle lookups in ~/.cache/novalyn/github-handles.json
+        #[arg(long, conflicts_with = "no_github_alias")]
+        cache_handles: bool,
     },
     /// Synchronize GitHub releases with local changelog data.
     Github {
         /// The git tag to sync as a GitHub release
         #[arg(long, short)]
         tag: String,
-        /// Path to file containing release body (defaults to changelog block)
+        /// Path to file containing release body (defaults to the tag's CHANGELOG.md block)
         #[arg(long, short)]
         body_path: Option<String>,
+        /// Changelog file to take the tag's block from instead of CHANGELOG.md
+        #[arg(long, short, value_name = "PATH", conflicts_with = "body_path")]
+        output: Option<String>,
+        /// Print the release request instead of sending it
+        #[arg(long, short)]
+        dry_run: bool,
+        /// Save the release as a draft
+        #[arg(long)]
+        draft: bool,
+        /// Mark the release as a prerelease (default: when the tag's version has a prerelease part)
+        #[arg(long)]
+        prerelease: bool,
+        /// Never mark the release as a prerelease
+        #[arg(long, conflicts_with = "prerelease")]
+        no_prerelease: bool,
     },
 }
 
+/// Output format of command summaries.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human readable summary line
+    #[default]
+    Text,
+    /// A single JSON object (an array with `--workspace`)
+    Json,
+}
+
+/// Markup of a generated release block.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChangelogFormat {
+    /// Markdown, as written to CHANGELOG.md
+    #[default]
+    Markdown,
+    /// reStructuredText, for Sphinx docs
+    Rst,
+}
+
 #[derive(Args, Debug)]
 pub struct Completions {
     /// The shell to generate completions for (e.g. bash, zsh, fish, powershell).
+    /// Defaults to the current shell, detected from $SHELL.
     #[arg(value_enum)]
-    pub shell: crate::shells::Shell,
+    pub shell: Option<crate::shells::Shell>,
 }
@@ -12,6 +12,6 @@ pub fn init(verbosity: usize) {
     let filter = std::env::var("RUST_LOG").unwrap_or_else(|_| format!("novalyn={level}"));
     let _ = tracing_subscriber::registry()
         .with(EnvFilter::new(filter))
-        .with(fmt::layer().with_target(true))
+        .with(fmt::layer().with_target(true).with_writer(std::io::stderr))
         .try_init();
 }
@@ -14,7 +14,7 @@ pub use novalyn as lib;
 async fn main() {
     novalyn_core::init_crypto_provider();
     match lib::cli::run().await {
-        Ok(exit_code) => std::process::exit(exit_code as i32),
+        Ok(exit_code) => std::process::exit(exit_code),
         Err(e) => {
             if let Some(err) = e.downcast_ref::<novalyn_core::error::NovalynError>() {
                 eprintln!("novalyn: {}", err);
@@ -80,3 +80,468 @@ fn cli_shell_completion_bash() {
     cmd.arg("completions").arg("bash");
     cmd.assert().success();
 }
+
+#[test]
+fn cli_shell_completion_from_env() {
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.env("SHELL", "/bin/bash").arg("completions");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("_novalyn()"));
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.env("SHELL", "/bin/unknown").arg("completions");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("pass one explicitly"));
+}
+
+#[test]
+fn cli_release_print_notes_only() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    std::fs::write(temp.path().join("a.txt"), "1").unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: one").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args(["release", "--print-notes-only", "--no-authors"]);
+    cmd.assert()
+        .success()
+        .stdout("## v0.0.1\n\n### ✨ Features\n* ✨: one\n");
+
+    assert!(!temp.path().join("CHANGELOG.md").exists());
+    assert_eq!(novalyn::git::last_tag(&repo).unwrap(), None);
+}
+
+/// Release twice so the second run produces no change.
+fn released_repo() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    std::fs::write(temp.path().join("a.txt"), "1").unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: one").unwrap();
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args(["release", "--yes", "--no-authors"]);
+    cmd.assert().success();
+    temp
+}
+
+#[test]
+fn cli_release_no_change_exit_code() {
+    let temp = released_repo();
+    let release = |extra: &[&str]| {
+        let mut cmd = cargo_bin_cmd!("novalyn");
+        cmd.current_dir(temp.path());
+        cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+        cmd.args(["release", "--yes", "--no-authors"]).args(extra);
+        cmd.assert()
+    };
+    release(&[])
+        .code(3)
+        .stdout(predicate::str::contains("No change for v0.0.1"));
+    release(&["--no-change-exit-code", "0"]).code(0);
+
+    std::fs::write(
+        temp.path().join("novalyn.toml"),
+        "no_change_exit_code = 42\nno_change_message = \"Nothing to release ({{newVersion}})\"\n",
+    )
+    .unwrap();
+    release(&[])
+        .code(42)
+        .stdout(predicate::str::contains("Nothing to release (0.0.1)"));
+    release(&["--no-change-exit-code", "7"]).code(7);
+}
+
+#[test]
+fn cli_release_treat_no_change_as_success() {
+    let temp = released_repo();
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args([
+        "release",
+        "--yes",
+        "--no-authors",
+        "--treat-no-change-as-success",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No change for v0.0.1"));
+}
+
+/// Point the repository's `origin` remote at a GitHub project.
+fn add_github_remote(path: &std::path::Path) {
+    let mut git_config = std::fs::OpenOptions::new()
+        .append(true)
+        .open(path.join(".git/config"))
+        .unwrap();
+    std::io::Write::write_all(
+        &mut git_config,
+        b"[remote \"origin\"]\n\turl = https://github.com/o/r.git\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn cli_github_dry_run_prints_payload() {
+    let temp = released_repo();
+    add_github_remote(temp.path());
+    std::fs::write(temp.path().join("notes.md"), "Release notes").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    // A token would normally trigger the API call; dry run must not make it
+    cmd.env("GITHUB_TOKEN", "dummy").env_remove("GH_TOKEN");
+    cmd.args([
+        "github",
+        "--tag",
+        "v0.0.1",
+        "--body-path",
+        "notes.md",
+        "--dry-run",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"tag_name\": \"v0.0.1\""))
+        .stdout(predicate::str::contains("\"body\": \"Release notes\""))
+        .stdout(predicate::str::contains("\"draft\": false"))
+        .stdout(predicate::str::contains("GitHub release").not());
+}
+
+#[test]
+fn cli_github_dry_run_reads_notes_from_output_file() {
+    let temp = released_repo();
+    add_github_remote(temp.path());
+    std::fs::rename(
+        temp.path().join("CHANGELOG.md"),
+        temp.path().join("HISTORY.md"),
+    )
+    .unwrap();
+
+    let github = |extra: &[&str]| {
+        let mut cmd = cargo_bin_cmd!("novalyn");
+        cmd.current_dir(temp.path());
+        cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+        cmd.args(["github", "--tag", "v0.0.1", "--dry-run"])
+            .args(extra);
+        cmd.assert().success()
+    };
+    github(&["--output", "HISTORY.md"])
+        .stdout(predicate::str::contains("✨: one"))
+        .stderr(predicate::str::contains("no notes").not());
+    github(&[])
+        .stdout(predicate::str::contains("\"body\": \"\""))
+        .stderr(predicate::str::contains("no notes for v0.0.1"));
+}
+
+#[test]
+fn cli_github_dry_run_draft_prerelease() {
+    let temp = released_repo();
+    add_github_remote(temp.path());
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args([
+        "github",
+        "--tag",
+        "v0.0.1",
+        "--dry-run",
+        "--draft",
+        "--prerelease",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"draft\": true"))
+        .stdout(predicate::str::contains("\"prerelease\": true"));
+}
+
+#[test]
+fn cli_github_body_defaults_to_changelog_block() {
+    let temp = released_repo();
+    add_github_remote(temp.path());
+    std::fs::write(
+        temp.path().join("CHANGELOG.md"),
+        "# Changelog\n\n## v0.1.0\n\n* newer\n\n## v0.0.1\n\n* older notes\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args(["github", "--tag", "v0.0.1", "--dry-run"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"body\": \"* older notes\\n\""));
+}
+
+#[test]
+fn cli_generate_json_format() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    std::fs::write(temp.path().join("a.txt"), "1").unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: one").unwrap();
+    std::fs::write(temp.path().join("b.txt"), "2").unwrap();
+    novalyn::git::add_and_commit(&mut repo, "fix: two").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args(["generate", "--no-authors", "--format", "json"]);
+    let out = cmd.assert().success().get_output().stdout.clone();
+    // stdout holds exactly one JSON document
+    let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(json["version"], "0.0.1");
+    assert_eq!(json["previous_version"], "0.0.0");
+    assert_eq!(json["bump"], "patch");
+    assert_eq!(json["commit_count"], 2);
+    assert_eq!(json["wrote"], false);
+    assert!(json.get("member").is_none());
+    let markdown = json["markdown"].as_str().unwrap();
+    assert!(markdown.starts_with("## v0.0.1"));
+    assert!(markdown.contains("* 🐞: two"));
+}
+
+#[test]
+fn cli_generate_json_includes_config_warnings() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    std::fs::write(temp.path().join("novalyn.toml"), "bogus_key = true\n").unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: one").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args(["generate", "--no-authors", "--format", "json"]);
+    let out = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    let warnings = json["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|w| w
+            .as_str()
+            .unwrap()
+            .contains("Unknown config key: bogus_key")),
+        "{warnings:?}"
+    );
+}
+
+#[test]
+fn cli_init_writes_starter_config_once() {
+    let temp = TempDir::new().unwrap();
+    let config = temp.path().join("novalyn.toml");
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path()).arg("init");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote"));
+    let written = std::fs::read_to_string(&config).unwrap();
+    assert!(written.contains("[types.feat]"));
+
+    // An existing file is left alone without --force
+    std::fs::write(&config, "compare_link = false\n").unwrap();
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path()).arg("init");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+    assert_eq!(
+        std::fs::read_to_string(&config).unwrap(),
+        "compare_link = false\n"
+    );
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path()).args(["init", "--stdout"]);
+    cmd.assert().success().stdout(written.clone());
+    assert_eq!(
+        std::fs::read_to_string(&config).unwrap(),
+        "compare_link = false\n"
+    );
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path()).args(["init", "--force"]);
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&config).unwrap(), written);
+}
+
+#[test]
+fn cli_output_selects_changelog_file() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: one").unwrap();
+    std::fs::create_dir(temp.path().join("docs")).unwrap();
+    std::fs::write(
+        temp.path().join("docs/CHANGES.md"),
+        "# Changes\n\n## v0.0.0\nOld\n",
+    )
+    .unwrap();
+
+    // "-" prints the block and writes nothing
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args(["release", "--no-authors", "--output", "-"]);
+    cmd.assert()
+        .success()
+        .stdout("## v0.0.1\n\n### ✨ Features\n* ✨: one\n");
+    assert!(!temp.path().join("CHANGELOG.md").exists());
+    assert_eq!(novalyn::git::last_tag(&repo).unwrap(), None);
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+    cmd.args([
+        "generate",
+        "--yes",
+        "--no-authors",
+        "--output",
+        "docs/CHANGES.md",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("updated CHANGES.md"));
+    let txt = std::fs::read_to_string(temp.path().join("docs/CHANGES.md")).unwrap();
+    assert!(txt.starts_with("# Changes\n\n## v0.0.1"), "{txt}");
+    assert!(txt.ends_with("## v0.0.0\nOld\n"), "{txt}");
+    assert!(!temp.path().join("CHANGELOG.md").exists());
+}
+
+#[test]
+fn cli_insert_after_backfills_release_block() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    novalyn::git::add_and_commit(&mut repo, "fix: missed").unwrap();
+    let changelog = temp.path().join("CHANGELOG.md");
+    std::fs::write(&changelog, "## v1.2.0\nNewest\n\n## v1.1.0\nOlder\n").unwrap();
+
+    let generate = |anchor: &str| {
+        let mut cmd = cargo_bin_cmd!("novalyn");
+        cmd.current_dir(temp.path());
+        cmd.env_remove("GITHUB_TOKEN").env_remove("GH_TOKEN");
+        cmd.args([
+            "generate",
+            "--yes",
+            "--no-authors",
+            "--new-version",
+            "1.1.1",
+            "--insert-after",
+            anchor,
+        ]);
+        cmd.assert()
+    };
+    generate("v1.0.0")
+        .failure()
+        .stderr(predicate::str::contains("release v1.0.0 not found"));
+    generate("v1.1.0").success();
+    let txt = std::fs::read_to_string(&changelog).unwrap();
+    assert!(txt.starts_with("## v1.2.0\nNewest\n\n## v1.1.1\n"), "{txt}");
+    assert!(txt.ends_with("* 🐞: missed\n\n## v1.1.0\nOlder\n"), "{txt}");
+    // The backfilled release is not at HEAD, so it must not be tagged
+    assert!(novalyn::git::last_tag(&repo).unwrap().is_none());
+}
+
+#[test]
+fn cli_exports_version_for_ci() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: initial").unwrap();
+    let github_output = temp.path().join("github_output");
+    std::fs::write(&github_output, "earlier=1\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env("GITHUB_OUTPUT", &github_output);
+    cmd.args([
+        "show",
+        "--new-version",
+        "1.2.0",
+        "--version-file",
+        "VERSION",
+        "--github-output",
+    ]);
+    cmd.assert().success().stdout("1.2.0\n");
+    let version = std::fs::read_to_string(temp.path().join("VERSION")).unwrap();
+    assert_eq!(version, "1.2.0\n");
+    let outputs = std::fs::read_to_string(&github_output).unwrap();
+    assert_eq!(outputs, "earlier=1\nversion=1.2.0\nbump=none\n");
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.env_remove("GITHUB_OUTPUT");
+    cmd.args(["show", "--github-output"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("GITHUB_OUTPUT"));
+}
+
+#[test]
+fn cli_interactive_skips_prompts_without_terminal() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    let config = "[keyword_types]\nFixed = \"fix\"\n";
+    std::fs::write(temp.path().join("novalyn.toml"), config).unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: initial").unwrap();
+    novalyn::git::add_and_commit(&mut repo, "Fixed crash on empty input").unwrap();
+    novalyn::git::add_and_commit(&mut repo, "WIP tidy up").unwrap();
+
+    // Piped stdin is not a terminal, so nothing is asked and nothing saved
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.args([
+        "generate",
+        "--interactive",
+        "--save-keyword-types",
+        "--no-github-alias",
+        "--output",
+        "-",
+    ]);
+    cmd.write_stdin("")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fixed crash on empty input"))
+        .stdout(predicate::str::contains("tidy up").not());
+    let saved = std::fs::read_to_string(temp.path().join("novalyn.toml")).unwrap();
+    assert_eq!(saved, config);
+}
+
+#[test]
+fn cli_generate_prints_rst() {
+    let temp = TempDir::new().unwrap();
+    let mut repo = novalyn::git::init_repo(temp.path()).unwrap();
+    novalyn::git::add_and_commit(&mut repo, "feat: initial").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.args([
+        "generate",
+        "--output-format",
+        "rst",
+        "--output",
+        "-",
+        "--new-version",
+        "1.0.0",
+        "--no-authors",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("v1.0.0\n======\n"))
+        .stdout(predicate::str::contains("* ✨: initial"));
+
+    // Changelog files stay markdown
+    let mut cmd = cargo_bin_cmd!("novalyn");
+    cmd.current_dir(temp.path());
+    cmd.args([
+        "generate",
+        "--output-format",
+        "rst",
+        "--output",
+        "CHANGES.rst",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("only markdown"));
+    assert!(!temp.path().join("CHANGES.rst").exists());
+}
@@ -84,6 +84,7 @@ fn authors_collection(bencher: Bencher, size: usize) {
             aliases: scc::HashMap::with_hasher(foldhash::quality::RandomState::default()),
             github_token: None,
             enable_github_aliasing: false,
+            prior_authors: None,
         };
         Authors::collect(&parsed, &opts)
     });
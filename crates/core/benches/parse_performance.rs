@@ -2,7 +2,7 @@ use divan::{AllocProfiler, Bencher};
 use mimalloc_safe::MiMalloc;
 use novalyn_core::config::{LoadOptions, load_config};
 use novalyn_core::git::RawCommit;
-use novalyn_core::parse::parse_and_classify;
+use novalyn_core::parse::{parse_and_classify, parse_and_classify_chunked};
 use std::env;
 use tempfile::TempDir;
 
@@ -82,6 +82,20 @@ fn parse_parallel(bencher: Bencher, size: usize) {
         .bench_values(|commits| parse_and_classify(commits.into(), &cfg));
 }
 
+#[divan::bench(args = [8, 64, 512])]
+fn parse_parallel_chunk_size(bencher: Bencher, chunk_size: usize) {
+    let td = TempDir::new().unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+
+    let commits = generate_synthetic_commits(5000);
+
+    bencher.bench(|| parse_and_classify_chunked(&commits, &cfg, chunk_size));
+}
+
 #[divan::bench(args = [10, 50, 100, 500])]
 fn version_inference(bencher: Bencher, size: usize) {
     let td = TempDir::new().unwrap();
@@ -127,6 +141,7 @@ fn render_block(bencher: Bencher, size: usize) {
                 repo: None,    // Skip repo for benchmark
                 cfg: &cfg,
                 previous_tag: Some("v0.9.0"),
+                first_commit: None,
                 current_ref: "HEAD",
             }
         })
@@ -1,4 +1,6 @@
+use crate::git::RawCommit;
 use crate::parse::ParsedCommit;
+use crate::repository::{Provider, Repository};
 use ecow::{EcoString, EcoVec};
 use once_cell::sync::Lazy;
 use unicode_normalization::UnicodeNormalization;
@@ -19,6 +21,11 @@ pub struct Author {
     pub name: EcoString,
     /// Author's email address (hidden if configured)
     pub email: Option<EcoString>,
+    /// Lowercased domain of the email, kept when the email is hidden
+    pub domain: Option<EcoString>,
+    /// No commits by this author precede the release (only set when prior
+    /// authors are known)
+    pub first_time: bool,
 }
 
 /// Collection of deduplicated authors from commit history.
@@ -49,6 +56,8 @@ pub struct AuthorOptions {
     pub github_token: Option<String>,
     /// Whether to resolve emails to @handles via GitHub API
     pub enable_github_aliasing: bool,
+    /// Names and emails of authors of earlier commits, used to flag first-time contributors
+    pub prior_authors: Option<FastHashSet<EcoString>>,
 }
 
 impl Default for AuthorOptions {
@@ -60,6 +69,84 @@ impl Default for AuthorOptions {
             aliases: FastHashMap::with_hasher(HASH_BUILDER.clone()),
             github_token: None,
             enable_github_aliasing: false,
+            prior_authors: None,
+        }
+    }
+}
+
+impl AuthorOptions {
+    /// Record the authors and co-authors (footers keyed by one of
+    /// `co_author_keys`) of `commits` as prior contributors.
+    ///
+    /// Identities are normalized and aliased like collected authors, so
+    /// [`Author::first_time`] is set for authors of the release that match
+    /// none of them by name or email.
+    pub fn set_prior_authors(
+        &mut self,
+        commits: impl IntoIterator<Item = RawCommit>,
+        co_author_keys: &[EcoString],
+    ) {
+        let prior = FastHashSet::with_hasher(HASH_BUILDER.clone());
+        let add = |name: &str, email: &str| {
+            let (name, email) = identity(name, email, self);
+            let _ = prior.insert_sync(name);
+            if let Some(email) = email {
+                let _ = prior.insert_sync(email);
+            }
+        };
+        for c in commits {
+            add(&c.author_name, &c.author_email);
+            for line in c.body.lines() {
+                if let Some((key, value)) = line.split_once(':')
+                    && co_author_keys
+                        .iter()
+                        .any(|k| key.trim().eq_ignore_ascii_case(k))
+                    && let Some((name, email)) = parse_co_author_line(value)
+                {
+                    add(name, email);
+                }
+            }
+        }
+        self.prior_authors = Some(prior);
+    }
+}
+
+/// Shared author policy read from the file named by the `authors_file` setting.
+///
+/// TOML (or JSON, for `.json` files) with an `exclude` list of names or emails
+/// and an `aliases` table mapping old identities to new ones.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct AuthorsFile {
+    pub exclude: EcoVec<EcoString>,
+    pub aliases: std::collections::BTreeMap<EcoString, EcoString>,
+}
+
+impl AuthorsFile {
+    /// Read and parse an authors file.
+    pub async fn load(path: &std::path::Path) -> anyhow::Result<Self> {
+        use anyhow::Context;
+        let txt = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Reading authors file {path:?}"))?;
+        if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&txt).with_context(|| format!("Parsing JSON {path:?}"))
+        } else {
+            toml_edit::de::from_str(&txt).with_context(|| format!("Parsing TOML {path:?}"))
+        }
+    }
+
+    /// Merge the file's exclusions and aliases into `opts`.
+    ///
+    /// Aliases already present in `opts` take precedence.
+    pub fn apply(&self, opts: &mut AuthorOptions) {
+        for entry in &self.exclude {
+            if !opts.exclude.contains(entry) {
+                opts.exclude.push(entry.clone());
+            }
+        }
+        for (from, to) in &self.aliases {
+            let _ = opts.aliases.insert_sync(normalize(from), normalize(to));
         }
     }
 }
@@ -103,39 +190,101 @@ impl Authors {
     ///
     /// # Arguments
     /// * `token` - GitHub API token for authentication
+    /// * `api_base` - REST API base URL (defaults to the public GitHub API)
+    /// * `cache` - Resolutions to consult first and record new lookups in
     ///
     /// # Returns
     /// * `Ok(())` - All resolutions completed (some may have failed silently)
     /// * `Err` - Critical error during resolution
-    pub async fn resolve_github_handles(&mut self, token: &str) -> Result<(), String> {
+    pub async fn resolve_github_handles(
+        &mut self,
+        token: &str,
+        api_base: Option<&str>,
+        mut cache: Option<&mut HandleCache>,
+    ) -> Result<(), String> {
         use crate::github::get_username_from_email;
         use futures::future::join_all;
 
-        // Collect all emails to resolve
+        // Look up each distinct address once, however many authors share it
         let authors_vec = self.list.make_mut();
-        let email_indices: Vec<(usize, String)> = authors_vec
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, author)| author.email.as_ref().map(|e| (idx, e.to_string())))
-            .collect();
+        let mut resolved: std::collections::HashMap<EcoString, Option<EcoString>> =
+            std::collections::HashMap::new();
+        let mut pending: Vec<EcoString> = Vec::new();
+        for email in authors_vec.iter().filter_map(|a| a.email.as_ref()) {
+            let key = email_key(email);
+            if resolved.contains_key(&key) || pending.contains(&key) {
+                continue;
+            }
+            match cache.as_deref().and_then(|c| c.get(&key)) {
+                Some(hit) => {
+                    resolved.insert(key, hit);
+                }
+                None => pending.push(key),
+            }
+        }
 
-        // Resolve all emails concurrently
-        let futures: Vec<_> = email_indices
+        // Resolve the remaining emails concurrently
+        let futures: Vec<_> = pending
             .iter()
-            .map(|(_, email)| get_username_from_email(email.as_str(), Some(token), None))
+            .map(|email| get_username_from_email(email.as_str(), Some(token), api_base))
             .collect();
 
         let results = join_all(futures).await;
 
+        // Failed lookups are neither applied nor cached; misses are cached too
+        for (email, result) in pending.into_iter().zip(results) {
+            if let Ok(handle) = result {
+                if let Some(cache) = cache.as_deref_mut() {
+                    cache.insert(email.clone(), handle.clone());
+                }
+                resolved.insert(email, handle);
+            }
+        }
+
         // Update authors with resolved handles
-        for ((idx, _), result) in email_indices.iter().zip(results.iter()) {
-            if let Ok(Some(handle)) = result {
-                authors_vec[*idx].name = handle.clone();
+        for author in authors_vec.iter_mut() {
+            if let Some(email) = &author.email
+                && let Some(Some(handle)) = resolved.get(&email_key(email))
+            {
+                author.name = handle.clone();
             }
         }
 
         Ok(())
     }
+
+    /// Resolve handles through the API of the detected repository provider.
+    ///
+    /// Only GitHub is supported; other providers are skipped without any network
+    /// calls. When no repository was detected, GitHub is assumed.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Resolution ran against the provider API
+    /// * `Ok(false)` - Resolution was skipped for this provider
+    pub async fn resolve_handles(
+        &mut self,
+        repo: Option<&Repository>,
+        token: &str,
+        api_base: Option<&str>,
+        cache: Option<&mut HandleCache>,
+    ) -> Result<bool, String> {
+        if let Some(repo) = repo
+            && repo.provider != Provider::GitHub
+        {
+            tracing::debug!(
+                provider = %repo.provider,
+                "handle resolution is only supported for GitHub; skipping"
+            );
+            return Ok(false);
+        }
+        self.resolve_github_handles(token, api_base, cache).await?;
+        Ok(true)
+    }
+}
+
+/// Key under which an email's handle is looked up and cached.
+fn email_key(email: &str) -> EcoString {
+    EcoString::from(email.trim().to_lowercase())
 }
 
 fn normalize(s: &str) -> EcoString {
@@ -154,13 +303,140 @@ fn excluded(opts: &AuthorOptions, name: &EcoString, email: Option<&EcoString>) -
     false
 }
 
-fn push_author<'a>(
-    out: &mut EcoVec<Author>,
-    seen: &mut FastHashSet<(EcoString, Option<EcoString>)>,
-    name: &'a str,
-    email: &'a str,
-    opts: &AuthorOptions,
-) {
+/// How long cached handle resolutions stay valid.
+pub const DEFAULT_HANDLE_CACHE_TTL: std::time::Duration =
+    std::time::Duration::from_secs(7 * 24 * 60 * 60);
+
+/// On-disk cache of email to GitHub handle resolutions (opt-in).
+///
+/// Stored as JSON keyed by lowercased email. Misses are cached as well, so
+/// addresses without a GitHub account aren't searched again until the entry
+/// expires.
+#[derive(Debug, Clone)]
+pub struct HandleCache {
+    path: std::path::PathBuf,
+    ttl: std::time::Duration,
+    entries: std::collections::BTreeMap<EcoString, HandleCacheEntry>,
+    dirty: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct HandleCacheEntry {
+    handle: Option<EcoString>,
+    /// Unix timestamp (seconds) of the lookup
+    resolved_at: u64,
+}
+
+impl HandleCache {
+    /// `$XDG_CACHE_HOME/novalyn/github-handles.json`, falling back to
+    /// `~/.cache/novalyn/github-handles.json`.
+    pub fn default_path() -> Option<std::path::PathBuf> {
+        let base = std::env::var_os("XDG_CACHE_HOME")
+            .filter(|v| !v.is_empty())
+            .map(std::path::PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .filter(|v| !v.is_empty())
+                    .map(|home| std::path::PathBuf::from(home).join(".cache"))
+            })?;
+        Some(base.join("novalyn").join("github-handles.json"))
+    }
+
+    /// Read the cache at `path`; a missing or unreadable file gives an empty cache.
+    pub async fn load(path: impl Into<std::path::PathBuf>, ttl: std::time::Duration) -> Self {
+        let path = path.into();
+        let entries = read_cache_entries(&path).await;
+        Self {
+            path,
+            ttl,
+            entries,
+            dirty: false,
+        }
+    }
+
+    /// Cached handle for `email`: `Some(None)` is a cached miss, `None` means
+    /// unknown or expired.
+    pub fn get(&self, email: &str) -> Option<Option<EcoString>> {
+        let entry = self.entries.get(&email_key(email))?;
+        (now_secs().saturating_sub(entry.resolved_at) < self.ttl.as_secs())
+            .then(|| entry.handle.clone())
+    }
+
+    /// Record the result of a lookup.
+    pub fn insert(&mut self, email: impl AsRef<str>, handle: Option<EcoString>) {
+        let entry = HandleCacheEntry {
+            handle,
+            resolved_at: now_secs(),
+        };
+        self.entries.insert(email_key(email.as_ref()), entry);
+        self.dirty = true;
+    }
+
+    /// Write new lookups back to disk.
+    ///
+    /// Entries written by other runs in the meantime are merged in (the newer
+    /// lookup wins), and the file is replaced atomically through a temporary
+    /// file so concurrent writers never leave it half-written.
+    pub async fn save(&self) -> anyhow::Result<()> {
+        use anyhow::Context;
+        if !self.dirty {
+            return Ok(());
+        }
+        let mut entries = read_cache_entries(&self.path).await;
+        for (email, entry) in &self.entries {
+            let newer = entries
+                .get(email)
+                .is_none_or(|e| e.resolved_at <= entry.resolved_at);
+            if newer {
+                entries.insert(email.clone(), entry.clone());
+            }
+        }
+        if let Some(dir) = self.path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .with_context(|| format!("Creating cache directory {dir:?}"))?;
+        }
+        let json = serde_json::to_string_pretty(&entries)?;
+        let tmp = self.path.with_extension(format!(
+            "json.{}.{}.tmp",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.subsec_nanos())
+                .unwrap_or_default()
+        ));
+        tokio::fs::write(&tmp, json)
+            .await
+            .with_context(|| format!("Writing handle cache {tmp:?}"))?;
+        if let Err(e) = tokio::fs::rename(&tmp, &self.path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(e).with_context(|| format!("Replacing handle cache {:?}", self.path));
+        }
+        Ok(())
+    }
+}
+
+async fn read_cache_entries(
+    path: &std::path::Path,
+) -> std::collections::BTreeMap<EcoString, HandleCacheEntry> {
+    match tokio::fs::read_to_string(path).await {
+        Ok(txt) => serde_json::from_str(&txt).unwrap_or_else(|e| {
+            tracing::debug!(path = %path.display(), error = %e, "ignoring unreadable handle cache");
+            Default::default()
+        }),
+        Err(_) => Default::default(),
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Normalized and aliased `(name, email)` of an author.
+fn identity(name: &str, email: &str, opts: &AuthorOptions) -> (EcoString, Option<EcoString>) {
     let mut name_n = normalize(name.trim());
     let mut email_n = if email.trim().is_empty() {
         None
@@ -177,7 +453,17 @@ fn push_author<'a>(
     {
         email_n = Some(alias_guard.get().clone());
     }
+    (name_n, email_n)
+}
 
+fn push_author<'a>(
+    out: &mut EcoVec<Author>,
+    seen: &mut FastHashSet<(EcoString, Option<EcoString>)>,
+    name: &'a str,
+    email: &'a str,
+    opts: &AuthorOptions,
+) {
+    let (name_n, email_n) = identity(name, email, opts);
     if excluded(opts, &name_n, email_n.as_ref()) {
         return;
     }
@@ -187,6 +473,13 @@ fn push_author<'a>(
         return;
     }
     let _ = seen.insert_sync(key);
+    let first_time = opts.prior_authors.as_ref().is_some_and(|prior| {
+        !prior.contains_sync(&name_n) && !email_n.as_ref().is_some_and(|e| prior.contains_sync(e))
+    });
+    let domain = email_n
+        .as_deref()
+        .and_then(|e| e.rsplit_once('@'))
+        .map(|(_, domain)| domain.to_lowercase().into());
     let email_final = if opts.hide_author_email {
         None
     } else {
@@ -195,6 +488,8 @@ fn push_author<'a>(
     out.push(Author {
         name: name_n,
         email: email_final,
+        domain,
+        first_time,
     });
 }
 
@@ -245,6 +540,7 @@ mod tests {
             co_authors: co.iter().map(|s| EcoString::from(*s)).collect(),
             type_cfg: None,
             index: 0,
+            ..Default::default()
         }
     }
 
@@ -319,4 +615,18 @@ mod tests {
         assert_eq!(a.list[0].name, "NewName");
         assert_eq!(a.list[0].email, Some(EcoString::from("new@example.com")));
     }
+
+    #[tokio::test]
+    async fn handle_resolution_skipped_for_gitlab() {
+        let commits = vec![mk_commit("Alice", "alice@example.com", &[])];
+        let mut a = Authors::collect(&commits, &AuthorOptions::default());
+        let repo = Repository::parse("https://gitlab.com/owner/project.git").unwrap();
+        // Returns before any request is built, so no GitHub API call is made
+        let resolved = a
+            .resolve_handles(Some(&repo), "token", None, None)
+            .await
+            .unwrap();
+        assert!(!resolved);
+        assert_eq!(a.list[0].name, "Alice");
+    }
 }
@@ -2,61 +2,208 @@ use ecow::EcoString;
 use std::path::Path;
 use tokio::fs;
 
-/// Write or prepend a new release block to CHANGELOG.md asynchronously.
-///
-/// This function handles idempotent updates - if the exact same release block
-/// already exists at the top of the changelog, no write occurs.
-///
-/// # Arguments
-/// * `path` - Directory containing CHANGELOG.md
-/// * `new_block` - New release block to prepend
-///
-/// # Returns
-/// * `Ok(true)` - File was modified with new content
-/// * `Ok(false)` - File unchanged (idempotent operation)
-/// * `Err` - I/O error occurred
+/// Options controlling how a release block is written into the changelog.
+#[derive(Debug, Clone, Default)]
+pub struct WriteOptions {
+    /// Text placed at the top of a changelog that has no title yet
+    pub intro: Option<EcoString>,
+    /// Write even if the existing changelog contains merge conflict markers
+    pub force: bool,
+    /// Backfill the block directly above this existing release instead of at the top
+    pub insert_after: Option<semver::Version>,
+}
+
+/// Whether `existing` contains unresolved merge conflict markers.
 ///
+/// Only the `<<<<<<<` and `>>>>>>>` lines are checked, since a bare `=======`
+/// line is also a valid setext heading underline.
+pub fn has_conflict_markers(existing: &str) -> bool {
+    existing.lines().any(|line| {
+        ["<<<<<<<", ">>>>>>>"].iter().any(|marker| {
+            line.strip_prefix(marker)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+        })
+    })
+}
+
+/// Refuse to touch a half-merged changelog unless forced.
+fn check_conflicts(file_path: &Path, existing: &str, opts: &WriteOptions) -> std::io::Result<()> {
+    if !opts.force && has_conflict_markers(existing) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "{} contains unresolved merge conflict markers; resolve them or pass --force",
+                file_path.display()
+            ),
+        ));
+    }
+    Ok(())
+}
+
 /// Internal helper to determine if changelog update is needed and prepare new content.
 /// Returns None if no update is needed, Some(new_content) if update should occur.
-fn prepare_changelog_update(existing: &str, new_block: &EcoString) -> Option<String> {
+///
+/// The new block is inserted below an existing top-level heading (and any text
+/// following it) so the file's own title style is kept. Without a heading the
+/// configured intro is added first, otherwise the block goes at the very top.
+fn prepare_changelog_update(
+    existing: &str,
+    new_block: &EcoString,
+    opts: &WriteOptions,
+) -> std::io::Result<Option<String>> {
     let mut normalized_new = new_block.trim_end().to_string();
     normalized_new.push('\n');
 
+    if let Some(anchor) = &opts.insert_after {
+        return backfill_block(existing, &normalized_new, anchor).map(Some);
+    }
+
     // Extract current first block (skip optional title line beginning with '# ' but not '## ')
     let top_block = extract_top_block(existing);
     if let Some(tb) = top_block
         && tb.trim_end() == normalized_new.trim_end()
     {
-        return None;
+        return Ok(None);
     }
 
-    // Direct quick check: if existing (after possible title) already begins with normalized_new
-    let existing_after_title = existing.strip_prefix("# Changelog\n").unwrap_or(existing);
-    if existing_after_title.starts_with(&normalized_new) {
-        return None;
+    let intro = opts
+        .intro
+        .as_deref()
+        .map(str::trim_end)
+        .filter(|i| !i.is_empty());
+    let (head, rest) = split_head(existing, intro);
+    if rest.starts_with(&normalized_new) {
+        return Ok(None);
     }
 
-    // Prepend new block before existing content (keeping single newline separation)
     let mut out = String::new();
+    let head = if head.is_empty() {
+        intro.unwrap_or("")
+    } else {
+        head
+    };
+    if !head.is_empty() {
+        out.push_str(head.trim_end());
+        out.push_str("\n\n");
+    }
     out.push_str(&normalized_new);
-    if !existing.starts_with('#') {
-        // unlikely
+    if !rest.is_empty() {
         out.push('\n');
+        out.push_str(rest);
+    }
+    Ok(Some(out))
+}
+
+/// Insert `block` directly above the `anchor` release, keeping the
+/// changelog in descending order.
+///
+/// Returns the content unchanged when the block is already there.
+fn backfill_block(
+    existing: &str,
+    block: &str,
+    anchor: &semver::Version,
+) -> std::io::Result<String> {
+    let mut offset = 0;
+    for line in existing.split_inclusive('\n') {
+        if is_release_heading(line.trim_end(), anchor) {
+            let (before, after) = existing.split_at(offset);
+            if before.trim_end().ends_with(block.trim_end()) {
+                return Ok(existing.to_string());
+            }
+            let mut out = before.trim_end().to_string();
+            if !out.is_empty() {
+                out.push_str("\n\n");
+            }
+            out.push_str(block);
+            out.push('\n');
+            out.push_str(after);
+            return Ok(out);
+        }
+        offset += line.len();
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!("release v{anchor} not found in the changelog; nothing to insert after"),
+    ))
+}
+
+/// Whether `line` is the `## v{version}` heading of a release block.
+fn is_release_heading(line: &str, version: &semver::Version) -> bool {
+    line.strip_prefix("## v")
+        .and_then(|rest| rest.strip_prefix(version.to_string().as_str()))
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+}
+
+/// Split a changelog into its leading title section and the remainder.
+///
+/// The title section is everything before the first `## ` line, but only when
+/// the file opens with a top-level `# ` heading or the configured intro.
+fn split_head<'a>(existing: &'a str, intro: Option<&str>) -> (&'a str, &'a str) {
+    let first = existing
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("");
+    let titled = first.starts_with("# ");
+    let has_intro = intro.is_some_and(|i| existing.trim_start().starts_with(i));
+    if !titled && !has_intro {
+        return ("", existing);
     }
-    out.push_str(existing);
-    Some(out)
+    let mut offset = 0;
+    for line in existing.split_inclusive('\n') {
+        if line.starts_with("## ") {
+            return existing.split_at(offset);
+        }
+        offset += line.len();
+    }
+    (existing, "")
 }
 
+/// Write or prepend a new release block to CHANGELOG.md asynchronously.
+///
+/// This function handles idempotent updates - if the exact same release block
+/// already exists at the top of the changelog, no write occurs.
+///
+/// # Arguments
+/// * `path` - Directory containing CHANGELOG.md
+/// * `new_block` - New release block to prepend
+///
+/// # Returns
+/// * `Ok(true)` - File was modified with new content
+/// * `Ok(false)` - File unchanged (idempotent operation)
+/// * `Err` - I/O error occurred
 pub async fn write_or_update_changelog_async(
     path: &Path,
     new_block: &EcoString,
 ) -> std::io::Result<bool> {
-    let file_path = path.join("CHANGELOG.md");
-    let existing = fs::read_to_string(&file_path)
-        .await
-        .unwrap_or_else(|_| "# Changelog\n".into());
-    if let Some(new_content) = prepare_changelog_update(&existing, new_block) {
-        fs::write(&file_path, new_content).await?;
+    write_changelog_with_async(path, new_block, &WriteOptions::default()).await
+}
+
+/// Like [`write_or_update_changelog_async`], with explicit [`WriteOptions`].
+pub async fn write_changelog_with_async(
+    path: &Path,
+    new_block: &EcoString,
+    opts: &WriteOptions,
+) -> std::io::Result<bool> {
+    write_changelog_file_async(&path.join("CHANGELOG.md"), new_block, opts).await
+}
+
+/// Like [`write_changelog_with_async`], for a changelog at `file_path`.
+///
+/// The file (and its parent directories) are created if missing.
+pub async fn write_changelog_file_async(
+    file_path: &Path,
+    new_block: &EcoString,
+    opts: &WriteOptions,
+) -> std::io::Result<bool> {
+    let existing = fs::read_to_string(file_path).await.unwrap_or_default();
+    check_conflicts(file_path, &existing, opts)?;
+    if let Some(new_content) =
+        prepare_changelog_update(&existing, new_block, opts)?.filter(|content| *content != existing)
+    {
+        if let Some(dir) = file_path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).await?;
+        }
+        fs::write(file_path, new_content).await?;
         Ok(true)
     } else {
         Ok(false)
@@ -68,10 +215,33 @@ pub async fn write_or_update_changelog_async(
 /// Consider using `write_or_update_changelog_async` for better performance
 /// when in an async context.
 pub fn write_or_update_changelog(path: &Path, new_block: &EcoString) -> std::io::Result<bool> {
-    let file_path = path.join("CHANGELOG.md");
-    let existing = std::fs::read_to_string(&file_path).unwrap_or_else(|_| "# Changelog\n".into());
-    if let Some(new_content) = prepare_changelog_update(&existing, new_block) {
-        std::fs::write(&file_path, new_content)?;
+    write_changelog_with(path, new_block, &WriteOptions::default())
+}
+
+/// Synchronous version of [`write_changelog_with_async`].
+pub fn write_changelog_with(
+    path: &Path,
+    new_block: &EcoString,
+    opts: &WriteOptions,
+) -> std::io::Result<bool> {
+    write_changelog_file(&path.join("CHANGELOG.md"), new_block, opts)
+}
+
+/// Synchronous version of [`write_changelog_file_async`].
+pub fn write_changelog_file(
+    file_path: &Path,
+    new_block: &EcoString,
+    opts: &WriteOptions,
+) -> std::io::Result<bool> {
+    let existing = std::fs::read_to_string(file_path).unwrap_or_default();
+    check_conflicts(file_path, &existing, opts)?;
+    if let Some(new_content) =
+        prepare_changelog_update(&existing, new_block, opts)?.filter(|content| *content != existing)
+    {
+        if let Some(dir) = file_path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(file_path, new_content)?;
         Ok(true)
     } else {
         Ok(false)
@@ -120,11 +290,52 @@ fn extract_top_block(existing: &str) -> Option<EcoString> {
     }
 }
 
+/// Extract the release notes for `version` from changelog content.
+///
+/// Finds the `## v{version}` block and returns the lines below its heading,
+/// up to the next release heading, with surrounding blank lines trimmed.
+///
+/// # Arguments
+/// * `existing` - Changelog file content
+/// * `version` - Release whose notes to extract
+///
+/// # Returns
+/// The notes if the release has a non-empty block, None otherwise
+pub fn release_notes(existing: &str, version: &semver::Version) -> Option<EcoString> {
+    let mut lines = existing.lines();
+    lines.find(|line| is_release_heading(line, version))?;
+    let notes: Vec<&str> = lines.take_while(|line| !line.starts_with("## ")).collect();
+    let notes = notes.join("\n");
+    let notes = notes.trim_matches('\n');
+    if notes.trim().is_empty() {
+        None
+    } else {
+        Some(format!("{notes}\n").into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    #[test]
+    fn release_notes_for_version() {
+        let existing = "# Changelog\n\n## v1.1.0\n\n### Fixes\n* b\n\n## v1.0.0\n\n* a\n";
+        let v = |s| semver::Version::parse(s).unwrap();
+        assert_eq!(
+            release_notes(existing, &v("1.1.0")).as_deref(),
+            Some("### Fixes\n* b\n")
+        );
+        assert_eq!(
+            release_notes(existing, &v("1.0.0")).as_deref(),
+            Some("* a\n")
+        );
+        assert_eq!(release_notes(existing, &v("1.0.1")), None);
+        // `v1.0.1` must not match a `v1.0.10` heading
+        assert_eq!(release_notes("## v1.0.10\n* c\n", &v("1.0.1")), None);
+    }
+
     #[test]
     fn prepends_when_missing() {
         let dir = tempdir().unwrap();
@@ -148,6 +359,46 @@ mod tests {
         assert!(txt.contains("## v1.0.0"));
     }
 
+    #[test]
+    fn custom_file_created_and_updated_under_title() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("docs/CHANGES.md");
+        let opts = WriteOptions::default();
+        assert!(write_changelog_file(&file, &EcoString::from("## v1.0.0\nOld\n"), &opts).unwrap());
+        std::fs::write(&file, "# Changes\n\n## v1.0.0\nOld\n").unwrap();
+        assert!(write_changelog_file(&file, &EcoString::from("## v1.1.0\nNew\n"), &opts).unwrap());
+        let txt = std::fs::read_to_string(&file).unwrap();
+        assert_eq!(txt, "# Changes\n\n## v1.1.0\nNew\n\n## v1.0.0\nOld\n");
+        assert!(!dir.path().join("CHANGELOG.md").exists());
+    }
+
+    #[test]
+    fn backfilled_block_inserted_above_anchor() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("CHANGELOG.md");
+        let original = "# Changelog\n\n## v1.2.0\nNewest\n\n## v1.1.0\nOlder\n";
+        std::fs::write(&file, original).unwrap();
+        let opts = WriteOptions {
+            insert_after: Some(semver::Version::new(1, 1, 0)),
+            ..Default::default()
+        };
+        let block = EcoString::from("## v1.1.1\nMissed\n");
+        assert!(write_changelog_file(&file, &block, &opts).unwrap());
+        let expected =
+            "# Changelog\n\n## v1.2.0\nNewest\n\n## v1.1.1\nMissed\n\n## v1.1.0\nOlder\n";
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), expected);
+        assert!(!write_changelog_file(&file, &block, &opts).unwrap());
+
+        let opts = WriteOptions {
+            insert_after: Some(semver::Version::new(1, 0, 0)),
+            ..Default::default()
+        };
+        let err = write_changelog_file(&file, &block, &opts).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("v1.0.0 not found"), "{err}");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), expected);
+    }
+
     #[test]
     fn idempotent_same_top_block() {
         let dir = tempdir().unwrap();
@@ -156,4 +407,91 @@ mod tests {
         let changed = write_or_update_changelog(dir.path(), &EcoString::from(block)).unwrap();
         assert!(!changed);
     }
+
+    #[test]
+    fn keeps_existing_title_style() {
+        for title in ["# Change Log", "# Changelog", "# Release history"] {
+            let dir = tempdir().unwrap();
+            let file = dir.path().join("CHANGELOG.md");
+            std::fs::write(&file, format!("{title}\n\n## v1.0.0\nOld\n")).unwrap();
+            let opts = WriteOptions {
+                intro: Some("# Changelog".into()),
+                ..Default::default()
+            };
+            write_changelog_with(dir.path(), &EcoString::from("## v1.1.0\nNew\n"), &opts).unwrap();
+            let txt = std::fs::read_to_string(&file).unwrap();
+            assert_eq!(
+                txt,
+                format!("{title}\n\n## v1.1.0\nNew\n\n## v1.0.0\nOld\n")
+            );
+        }
+    }
+
+    #[test]
+    fn inserts_below_title_and_intro_text() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("CHANGELOG.md");
+        std::fs::write(
+            &file,
+            "# Changelog\n\nAll notable changes.\n\n## v1.0.0\nOld\n",
+        )
+        .unwrap();
+        write_or_update_changelog(dir.path(), &EcoString::from("## v1.1.0\nNew\n")).unwrap();
+        let txt = std::fs::read_to_string(&file).unwrap();
+        assert_eq!(
+            txt,
+            "# Changelog\n\nAll notable changes.\n\n## v1.1.0\nNew\n\n## v1.0.0\nOld\n"
+        );
+    }
+
+    #[test]
+    fn untitled_file_gets_block_at_top() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("CHANGELOG.md");
+        std::fs::write(&file, "## v1.0.0\nOld\n").unwrap();
+        write_or_update_changelog(dir.path(), &EcoString::from("## v1.1.0\nNew\n")).unwrap();
+        let txt = std::fs::read_to_string(&file).unwrap();
+        assert_eq!(txt, "## v1.1.0\nNew\n\n## v1.0.0\nOld\n");
+    }
+
+    #[test]
+    fn intro_added_once_without_title() {
+        let dir = tempdir().unwrap();
+        let opts = WriteOptions {
+            intro: Some("# Changelog\n\nAll notable changes.\n".into()),
+            ..Default::default()
+        };
+        write_changelog_with(dir.path(), &EcoString::from("## v1.0.0\nOld\n"), &opts).unwrap();
+        write_changelog_with(dir.path(), &EcoString::from("## v1.1.0\nNew\n"), &opts).unwrap();
+        let txt = std::fs::read_to_string(dir.path().join("CHANGELOG.md")).unwrap();
+        assert_eq!(
+            txt,
+            "# Changelog\n\nAll notable changes.\n\n## v1.1.0\nNew\n\n## v1.0.0\nOld\n"
+        );
+    }
+
+    #[test]
+    fn refuses_conflicted_changelog_unless_forced() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("CHANGELOG.md");
+        let conflicted = "# Changelog\n\n<<<<<<< HEAD\n## v1.1.0\nOurs\n=======\n## v1.1.0\nTheirs\n>>>>>>> feature\n";
+        std::fs::write(&file, conflicted).unwrap();
+        let block = EcoString::from("## v1.2.0\nNew\n");
+        let err = write_or_update_changelog(dir.path(), &block).unwrap_err();
+        assert!(err.to_string().contains("merge conflict markers"));
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), conflicted);
+
+        // The error names the file being written
+        let history = dir.path().join("HISTORY.md");
+        std::fs::write(&history, conflicted).unwrap();
+        let err = write_changelog_file(&history, &block, &WriteOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("HISTORY.md"), "{err}");
+
+        let opts = WriteOptions {
+            force: true,
+            ..Default::default()
+        };
+        assert!(write_changelog_with(dir.path(), &block, &opts).unwrap());
+        assert!(!has_conflict_markers("Title\n=======\n"));
+    }
 }
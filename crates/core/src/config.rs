@@ -7,13 +7,13 @@ use std::{
 use anyhow::{Context, Result};
 use ecow::{EcoString, EcoVec};
 use semver::Version;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 /// Configuration for commit type display and classification.
 ///
 /// Can be either a boolean toggle or a full configuration object.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum TypeToggleOrConfig {
     /// false means disabled, true treated as default object
@@ -22,17 +22,28 @@ pub enum TypeToggleOrConfig {
     Config(TypeConfigPartial),
 }
 
+/// The `[github]` table.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct GithubConfig {
+    /// REST API base URL, e.g. "https://github.mycorp.com/api/v3" for GitHub Enterprise
+    pub api_url: Option<EcoString>,
+}
+
 /// Partial configuration for a commit type (from TOML).
 ///
 /// All fields are optional to allow incremental configuration.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct TypeConfigPartial {
     /// Display title for this commit type
     pub title: Option<EcoString>,
+    /// Title used instead when the section has a single entry
+    pub singular_title: Option<EcoString>,
     /// Emoji prefix for changelog entries
     pub emoji: Option<EcoString>,
     /// Semantic version impact: "major" | "minor" | "patch" | "none"
     pub semver: Option<EcoString>,
+    /// Link issue/PR references of this type's entries (default true)
+    pub link_refs: Option<bool>,
 }
 
 /// Fully resolved configuration for a commit type.
@@ -44,12 +55,16 @@ pub struct TypeConfigResolved {
     pub key: EcoString,
     /// Display title for changelog sections
     pub title: EcoString,
+    /// Title for sections with a single entry (falls back to `title`)
+    pub singular_title: Option<EcoString>,
     /// Emoji prefix for entries
     pub emoji: EcoString,
     /// Semantic versioning impact
     pub semver: SemverImpact,
     /// Whether this type is enabled for display
     pub enabled: bool,
+    /// Whether issue/PR references of this type's entries are linked
+    pub link_refs: bool,
 }
 
 /// Semantic version impact level for a commit type.
@@ -67,6 +82,52 @@ pub enum SemverImpact {
     None,
 }
 
+/// How the previous release tag is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreviousTagStrategy {
+    /// Most recent semver tag by commit time
+    #[default]
+    Latest,
+    /// Highest semver tag reachable from the release head
+    SemverReachable,
+}
+
+/// Bump applied by minor-impact commits (e.g. `feat`) while the major version is 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZeroMajorFeat {
+    /// Keep bumping the minor version
+    Minor,
+    /// Downgrade to a patch bump (cautious 0.x default)
+    #[default]
+    Patch,
+}
+
+/// Order of the per-type sections in a release block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionSort {
+    /// Order types are configured in
+    #[default]
+    Config,
+    /// Highest semver impact first, then config order
+    Impact,
+}
+
+/// What to do with commits whose description is empty (e.g. `feat: `).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnEmptyDescription {
+    /// Drop the commit with a warning
+    Skip,
+    /// Use the raw commit summary as the description
+    #[default]
+    Summary,
+    /// Render the empty description as is
+    Keep,
+}
+
 impl SemverImpact {
     pub fn parse(s: &str) -> Option<Self> {
         match s {
@@ -77,36 +138,492 @@ impl SemverImpact {
             _ => None,
         }
     }
+
+    /// Config spelling, the inverse of [`SemverImpact::parse`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Patch => "patch",
+            Self::None => "none",
+        }
+    }
+
+    /// Sort rank, most impactful first.
+    pub fn rank(self) -> u8 {
+        match self {
+            Self::Major => 0,
+            Self::Minor => 1,
+            Self::Patch => 2,
+            Self::None => 3,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct RawConfig {
     pub new_version: Option<EcoString>,
     #[serde(rename = "types")]
     pub types_override: Option<BTreeMap<EcoString, TypeToggleOrConfig>>, // allow disabling or overriding
-    pub scope_map: Option<BTreeMap<EcoString, EcoString>>, // future
+    pub scope_map: Option<BTreeMap<EcoString, EcoString>>, // exact keys, or regexes prefixed with "re:"
     pub hide_author_email: Option<bool>,
     pub no_authors: Option<bool>,
+    /// Shared TOML/JSON file with author `exclude` and `aliases` (relative to cwd)
+    pub authors_file: Option<PathBuf>,
+    /// Fetch labels of referenced pull requests from the GitHub API
+    pub fetch_pr_labels: Option<bool>,
+    /// Abbreviate full SHAs used as compare link endpoints (default true)
+    pub compare_short_sha: Option<bool>,
+    /// Render the compare link under each release heading (default true)
+    pub compare_link: Option<bool>,
+    /// Render the git range and commit count under each release heading (default false)
+    pub show_range: Option<bool>,
+    /// Prefix entries of emoji-less types with the type key (default false)
+    pub show_type_label: Option<bool>,
+    /// Show type emoji in section headings and entries (default true)
+    pub emoji: Option<bool>,
+    /// Scopes that make a release fail when used by an in-range commit
+    pub banned_scopes: Option<EcoVec<EcoString>>,
+    /// Shell commands run before a release writes the changelog; a failure aborts it
+    pub pre_release_hooks: Option<EcoVec<EcoString>>,
+    /// Shell commands run after a release is tagged; failures only warn
+    pub post_release_hooks: Option<EcoVec<EcoString>>,
+    /// Heading for breaking commits of disabled or unknown types ("" hides them)
+    pub breaking_section_title: Option<EcoString>,
+    /// List every breaking commit in the breaking section (default false)
+    pub group_breaking: Option<bool>,
+    /// With `group_breaking`, leave breaking commits out of type sections (default false)
+    pub dedupe_breaking: Option<bool>,
+    /// Section order: "config" (default) or "impact"
+    pub section_sort: Option<SectionSort>,
+    /// Show at most this many issue references per entry (default unlimited)
+    pub max_refs_per_entry: Option<usize>,
+    /// Prefix references closed by a keyword (`Closes #1`) with "closes" (default false)
+    pub show_closed_issues: Option<bool>,
+    /// Wrap type sections with more entries than this in a `<details>` block (default never)
+    pub collapse_threshold: Option<usize>,
+    /// Footer keys (e.g. "Reviewed-by") rendered after each entry
+    pub render_footers: Option<EcoVec<EcoString>>,
+    /// Entry line template with `{emoji}`, `{scope}`, `{description}`, `{hash}`,
+    /// `{shortHash}`, `{hashUrl}`, `{issues}` and `{breaking}` tokens
+    pub commit_template: Option<EcoString>,
+    /// Footer keys naming co-authors, matched case-insensitively (default ["Co-authored-by"])
+    pub co_author_keys: Option<EcoVec<EcoString>>,
+    /// Render top-level bullets of commit bodies as nested items (default false)
+    pub expand_body_bullets: Option<bool>,
+    /// Append the linked short commit id to each entry (default false)
+    pub link_commits: Option<bool>,
+    /// List authors without commits before the release under "New Contributors" (default false)
+    pub new_contributors: Option<bool>,
+    /// Email domain to subheading label for grouping contributors (e.g. `"corp.com" = "Corp"`)
+    pub contributor_groups: Option<BTreeMap<EcoString, EcoString>>,
+    /// Drop commits whose changes all fall under these paths
+    pub exclude_paths: Option<EcoVec<PathBuf>>,
+    /// Leave commits without a conventional header out of the changelog
+    pub strict_conventional: Option<bool>,
+    /// Type for non-conventional commits by the first word of their summary
+    /// (e.g. `Fixed = "fix"`), matched case-insensitively
+    pub keyword_types: Option<BTreeMap<EcoString, EcoString>>,
+    /// Alternative type names mapped to a configured type (e.g. `feature = "feat"`)
+    pub type_aliases: Option<BTreeMap<EcoString, EcoString>>,
+    /// Accept `type/scope: description` headers (default false)
+    pub slash_scope: Option<bool>,
+    /// Text placed at the top of a new (or untitled) changelog
+    pub intro: Option<EcoString>,
+    /// Template appended to each release block (`{{newVersion}}`,
+    /// `{{previousVersion}}`, `{{date}}` and `{{env.VAR}}` tokens)
+    pub outro: Option<EcoString>,
+    /// Strategy for picking the previous release tag
+    pub previous_tag_strategy: Option<PreviousTagStrategy>,
+    /// Without a release tag, start from the Cargo.toml `package.version` (default true)
+    pub manifest_version_fallback: Option<bool>,
+    /// Bump applied by `feat` commits on 0.x versions
+    pub zero_major_feat: Option<ZeroMajorFeat>,
+    /// Smallest bump for any release with commits ("none", "patch", "minor", "major")
+    pub min_bump: Option<BumpKind>,
+    /// Produce prereleases with this identifier, e.g. "rc.{{n}}" or "nightly.{{date}}.{{n}}"
+    pub prerelease_format: Option<EcoString>,
+    /// Marker opting a commit out of the changelog (empty string disables)
+    pub skip_marker: Option<EcoString>,
+    /// Drop non-breaking `chore(deps...)` commits (default true)
+    pub filter_chore_deps: Option<bool>,
+    /// Regexes matched against commit summaries; matching commits are dropped
+    pub skip_commit_patterns: Option<Vec<String>>,
+    /// Summary of release commits made by automation, which are dropped
+    /// (`{{newVersion}}` matches any version; empty string disables)
+    pub release_commit_template: Option<EcoString>,
+    /// Prefix placed before the version in tag names (default "v")
+    pub tag_prefix: Option<EcoString>,
+    /// Full tag name template with a `{{newVersion}}` token (and optional
+    /// `{{crateName}}`); overrides `tag_prefix`
+    pub tag_format: Option<EcoString>,
+    /// Prefix for `revert` entries, followed by the reverted subject (default "Revert: ")
+    pub revert_prefix: Option<EcoString>,
+    /// Leave out commits reverted in the same release, and their reverts (default false)
+    pub hide_reverted: Option<bool>,
+    /// Handling of commits with an empty description
+    pub on_empty_description: Option<OnEmptyDescription>,
+    /// Footer key holding upgrade instructions (default "Migration")
+    pub migration_footer: Option<EcoString>,
+    /// Footer key whose value overrides a commit's type (default "Changelog-Category")
+    pub category_footer: Option<EcoString>,
+    /// Process exit code for runs that produce no change (default 3)
+    pub no_change_exit_code: Option<i32>,
+    /// Message printed when a release produces no change (`{{newVersion}}` token)
+    pub no_change_message: Option<EcoString>,
+    /// GitHub release title (`{{newVersion}}`, `{{previousVersion}}`, `{{date}}` tokens)
+    pub release_name_template: Option<EcoString>,
+    /// GitHub API settings
+    pub github: Option<GithubConfig>,
+    /// Ignore `[package.metadata.novalyn]` in Cargo.toml (honored in novalyn.toml
+    /// and CLI overrides)
+    pub no_cargo_metadata: Option<bool>,
     // capture unknown keys (flatten) for warning emission
     #[serde(flatten)]
     pub _unknown: BTreeMap<String, serde_json::Value>,
 }
 
+impl RawConfig {
+    /// CLI override layer for `--no-metadata`.
+    pub fn without_metadata() -> Self {
+        Self {
+            no_cargo_metadata: Some(true),
+            ..Default::default()
+        }
+    }
+}
+
 // Access repository module via crate root (this crate)
+use crate::parse::BumpKind;
 use crate::repository as repo_mod; // binary crate re-exports via main, lib via lib.rs
 
+/// Default marker that opts a commit out of the changelog.
+pub const DEFAULT_SKIP_MARKER: &str = "[skip changelog]";
+
+/// Default summary of release commits, as written by changelogen.
+pub const DEFAULT_RELEASE_COMMIT_TEMPLATE: &str = "chore(release): v{{newVersion}}";
+
+/// Default prefix for `revert` entries.
+pub const DEFAULT_REVERT_PREFIX: &str = "Revert: ";
+
+/// Default footer key naming a co-author.
+pub const DEFAULT_CO_AUTHOR_KEY: &str = "Co-authored-by";
+
+/// Default footer key for migration guides.
+pub const DEFAULT_MIGRATION_FOOTER: &str = "Migration";
+
+/// Default footer key overriding a commit's type.
+pub const DEFAULT_CATEGORY_FOOTER: &str = "Changelog-Category";
+
+/// Default message printed when a release produces no change.
+pub const DEFAULT_NO_CHANGE_MESSAGE: &str = "No change for v{{newVersion}}";
+
+/// Prefix marking a `scope_map` key as a regular expression.
+pub const SCOPE_REGEX_PREFIX: &str = "re:";
+
+/// Default heading for breaking commits whose type section is hidden.
+pub const DEFAULT_BREAKING_SECTION_TITLE: &str = "⚠️ Breaking Changes";
+
+/// Subheading for contributors whose domain has no `contributor_groups` entry.
+pub const DEFAULT_CONTRIBUTOR_GROUP: &str = "Other";
+
 #[derive(Debug, Clone)]
 pub struct ResolvedConfig {
     // Optional scope mapping (exact match) applied after parsing
     pub scope_map: std::collections::BTreeMap<EcoString, EcoString>,
+    /// Compiled `re:` entries of `scope_map` with their replacements, in key order.
+    ///
+    /// Only consulted when no exact entry matches; the first matching pattern
+    /// wins and replaces the matched part of the scope (`$1` etc. expand).
+    pub scope_patterns: Vec<(regex::Regex, EcoString)>,
     pub types: Vec<TypeConfigResolved>,
     pub new_version: Option<Version>,
     pub warnings: EcoVec<EcoString>,
     pub github_token: Option<EcoString>,
+    /// GitHub REST API base URL (`NOVALYN_GITHUB_API`, then `github.api_url`,
+    /// then derived from an Enterprise remote host)
+    pub github_api_url: EcoString,
     pub cwd: PathBuf,
     pub source_file: Option<PathBuf>,
+    /// `[package].name` of the Cargo.toml in `cwd`, if any
+    pub crate_name: Option<EcoString>,
     pub repo: Option<repo_mod::Repository>, // set by detection (best-effort)
+    /// Annotate entries with labels of their pull request (requires a GitHub token)
+    pub fetch_pr_labels: bool,
+    /// Abbreviate full SHAs used as compare link endpoints
+    pub compare_short_sha: bool,
+    /// Render a compare link under the release heading
+    pub compare_link: bool,
+    /// Render a `_Changes from A to B (N commits)_` line under the release heading
+    pub show_range: bool,
+    /// Show the type key on entries whose type has no emoji
+    pub show_type_label: bool,
+    /// Render type emoji; when false every type renders as if it had none
+    pub emoji: bool,
+    /// Scopes no released commit may use
+    pub banned_scopes: EcoVec<EcoString>,
+    /// Commands run before writing a release (`{{newVersion}}` etc. interpolated)
+    pub pre_release_hooks: EcoVec<EcoString>,
+    /// Commands run after tagging a release
+    pub post_release_hooks: EcoVec<EcoString>,
+    /// Heading of the section listing breaking commits no type section shows
+    pub breaking_section_title: EcoString,
+    /// Collect all breaking commits, with their `BREAKING CHANGE` notes, in the breaking section
+    pub group_breaking: bool,
+    /// Show grouped breaking commits only in the breaking section
+    pub dedupe_breaking: bool,
+    /// Order of the per-type sections
+    pub section_sort: SectionSort,
+    /// Issue references shown per entry before collapsing into "+N more"
+    pub max_refs_per_entry: Option<usize>,
+    /// Render keyword-closed references as `closes #N`
+    pub show_closed_issues: bool,
+    /// Entry count above which a type section is collapsed into `<details>`
+    pub collapse_threshold: Option<usize>,
+    /// Footer keys surfaced on entries, matched case-insensitively
+    pub render_footers: EcoVec<EcoString>,
+    /// Entry line template (None keeps the built-in `* emoji(scope): description` format)
+    pub commit_template: Option<EcoString>,
+    /// Footer keys whose values are collected as co-authors
+    pub co_author_keys: EcoVec<EcoString>,
+    /// Render `- ` / `* ` bullets of an entry's body as sub-items
+    pub expand_body_bullets: bool,
+    /// End built-in entry lines with `([shortId](commit_url))`
+    pub link_commits: bool,
+    /// Add a "New Contributors" subsection for first-time authors
+    pub new_contributors: bool,
+    /// Lowercased email domain to contributor subheading; empty lists contributors flat
+    pub contributor_groups: BTreeMap<EcoString, EcoString>,
+    /// Commits touching only these paths are left out of the changelog
+    pub exclude_paths: EcoVec<PathBuf>,
+    /// Exclude (and count) commits whose summary is not a conventional header
+    pub strict_conventional: bool,
+    /// Lowercased summary keyword to the type given to non-conventional commits
+    pub keyword_types: BTreeMap<EcoString, EcoString>,
+    /// Lowercased alternative type name to the type it stands for
+    pub type_aliases: BTreeMap<EcoString, EcoString>,
+    /// Parse `type/scope:` as well as `type(scope):` headers
+    pub slash_scope: bool,
+    /// Changelog intro added when the file has no top-level heading
+    pub intro: Option<EcoString>,
+    /// Template interpolated at the end of each release block
+    pub outro: Option<EcoString>,
+    /// Strategy for picking the previous release tag
+    pub previous_tag_strategy: PreviousTagStrategy,
+    /// Without a release tag, start from the Cargo.toml `package.version`
+    pub manifest_version_fallback: bool,
+    /// Bump applied by `feat` commits on 0.x versions
+    pub zero_major_feat: ZeroMajorFeat,
+    /// Floor for the inferred bump; an explicit new version still wins
+    pub min_bump: BumpKind,
+    /// Prerelease identifier format (`{{n}}` counter, `{{date}}`); unset for stable releases
+    pub prerelease_format: Option<EcoString>,
+    /// Shared author policy file, relative to `cwd`
+    pub authors_file: Option<PathBuf>,
+    /// Commits whose message contains this marker are dropped (empty disables)
+    pub skip_marker: EcoString,
+    /// Drop non-breaking `chore(deps...)` commits
+    pub filter_chore_deps: bool,
+    /// Compiled `skip_commit_patterns`; commits whose summary matches are dropped
+    pub skip_commit_patterns: Vec<regex::Regex>,
+    /// `release_commit_template` as a regex over whole summaries (None when disabled)
+    pub release_commit_pattern: Option<regex::Regex>,
+    /// Prefix placed before the version in tag names
+    pub tag_prefix: EcoString,
+    /// Full tag name template (`{{newVersion}}` token), taking precedence over `tag_prefix`
+    pub tag_format: Option<EcoString>,
+    /// Prefix for `revert` entries (empty renders the description unchanged)
+    pub revert_prefix: EcoString,
+    /// Drop reverted commits and the reverts undoing them when both are in the release
+    pub hide_reverted: bool,
+    /// Handling of commits with an empty description
+    pub on_empty_description: OnEmptyDescription,
+    /// Footer key whose value is rendered as a migration guide (empty disables)
+    pub migration_footer: EcoString,
+    /// Footer key whose value replaces the commit type (empty disables)
+    pub category_footer: EcoString,
+    /// Process exit code for runs that produce no change
+    pub no_change_exit_code: i32,
+    /// Message printed when a release produces no change (`{{newVersion}}` token)
+    pub no_change_message: EcoString,
+    /// GitHub release title template; the tag name is used when unset
+    pub release_name_template: Option<EcoString>,
+    /// Loaded config layers, lowest precedence first
+    pub sources: EcoVec<ConfigSource>,
+}
+
+/// A config layer that was found and loaded, with the keys it contributed.
+#[derive(Debug, Clone)]
+pub struct ConfigSource {
+    /// Human readable source description (file path or "CLI overrides")
+    pub name: EcoString,
+    /// Keys whose merged value comes from this layer
+    pub keys: EcoVec<EcoString>,
+    /// Keys set here but overridden by a higher-precedence layer
+    pub overridden: EcoVec<EcoString>,
+}
+
+/// Token replaced by the version in `tag_format`.
+const TAG_VERSION_TOKEN: &str = "{{newVersion}}";
+
+/// Token replaced by the crate name in `tag_format`.
+const CRATE_NAME_TOKEN: &str = "{{crateName}}";
+
+impl ResolvedConfig {
+    /// Tag name for a release of `version`.
+    pub fn tag_name(&self, version: &Version) -> EcoString {
+        match &self.tag_format {
+            Some(format) => format.replace(TAG_VERSION_TOKEN, &version.to_string()),
+            None => format!("{}{}", self.tag_prefix, version).into(),
+        }
+    }
+
+    /// The no-change message for `version`.
+    pub fn no_change_message_for(&self, version: &Version) -> EcoString {
+        self.no_change_message
+            .replace(TAG_VERSION_TOKEN, &version.to_string())
+    }
+
+    /// GitHub release title for `tag`, from `release_name_template` if set.
+    ///
+    /// `{{previousVersion}}` expands to an empty string for a first release.
+    pub fn release_name(
+        &self,
+        tag: &str,
+        version: &Version,
+        previous: Option<&Version>,
+        date: &str,
+    ) -> EcoString {
+        match &self.release_name_template {
+            Some(template) => template
+                .replace(TAG_VERSION_TOKEN, &version.to_string())
+                .replace(
+                    "{{previousVersion}}",
+                    &previous.map(ToString::to_string).unwrap_or_default(),
+                )
+                .replace("{{date}}", date),
+            None => tag.into(),
+        }
+    }
+
+    /// `prerelease_format` with `{{date}}` expanded, ready for version inference.
+    pub fn prerelease_format_on(&self, date: &str) -> Option<EcoString> {
+        self.prerelease_format
+            .as_ref()
+            .map(|format| format.replace("{{date}}", date))
+    }
+
+    /// Whether any loaded config layer set `key` explicitly.
+    pub fn is_set(&self, key: &str) -> bool {
+        self.sources.iter().any(|s| s.keys.iter().any(|k| k == key))
+    }
+
+    /// Describe the loaded config layers and the keys each contributed,
+    /// lowest precedence first.
+    pub fn describe_sources(&self) -> String {
+        let mut out = String::from("Config sources (lowest precedence first):\n");
+        if self.sources.is_empty() {
+            out.push_str("  (none; built-in defaults only)\n");
+        }
+        for (i, source) in self.sources.iter().enumerate() {
+            out.push_str(&format!("  {}. {}\n", i + 1, source.name));
+            if !source.keys.is_empty() {
+                out.push_str(&format!("     contributes: {}\n", source.keys.join(", ")));
+            }
+            if !source.overridden.is_empty() {
+                out.push_str(&format!(
+                    "     overridden: {}\n",
+                    source.overridden.join(", ")
+                ));
+            }
+        }
+        out
+    }
+
+    /// Parse the version back out of a tag name produced by [`Self::tag_name`].
+    ///
+    /// Without a `tag_format` the prefix is optional, so bare `1.2.3` tags
+    /// are still recognized.
+    pub fn parse_tag(&self, tag: &str) -> Option<Version> {
+        let version = match &self.tag_format {
+            Some(format) => {
+                let (before, after) = format.split_once(TAG_VERSION_TOKEN)?;
+                tag.strip_prefix(before)?.strip_suffix(after)?
+            }
+            None => tag.strip_prefix(self.tag_prefix.as_str()).unwrap_or(tag),
+        };
+        Version::parse(version).ok()
+    }
+}
+
+impl Default for ResolvedConfig {
+    fn default() -> Self {
+        Self {
+            scope_map: BTreeMap::new(),
+            scope_patterns: Vec::new(),
+            types: default_types(),
+            new_version: None,
+            warnings: EcoVec::new(),
+            github_token: None,
+            github_api_url: crate::github::DEFAULT_API_BASE.into(),
+            cwd: PathBuf::from("."),
+            source_file: None,
+            crate_name: None,
+            repo: None,
+            fetch_pr_labels: false,
+            compare_short_sha: true,
+            compare_link: true,
+            show_range: false,
+            show_type_label: false,
+            emoji: true,
+            banned_scopes: EcoVec::new(),
+            pre_release_hooks: EcoVec::new(),
+            post_release_hooks: EcoVec::new(),
+            breaking_section_title: DEFAULT_BREAKING_SECTION_TITLE.into(),
+            group_breaking: false,
+            dedupe_breaking: false,
+            section_sort: SectionSort::Config,
+            max_refs_per_entry: None,
+            show_closed_issues: false,
+            collapse_threshold: None,
+            render_footers: EcoVec::new(),
+            commit_template: None,
+            co_author_keys: EcoVec::from([EcoString::from(DEFAULT_CO_AUTHOR_KEY)]),
+            expand_body_bullets: false,
+            link_commits: false,
+            new_contributors: false,
+            contributor_groups: BTreeMap::new(),
+            exclude_paths: EcoVec::new(),
+            strict_conventional: false,
+            keyword_types: BTreeMap::new(),
+            type_aliases: BTreeMap::new(),
+            slash_scope: false,
+            intro: None,
+            outro: None,
+            previous_tag_strategy: PreviousTagStrategy::Latest,
+            manifest_version_fallback: true,
+            zero_major_feat: ZeroMajorFeat::Patch,
+            min_bump: BumpKind::None,
+            prerelease_format: None,
+            authors_file: None,
+            skip_marker: DEFAULT_SKIP_MARKER.into(),
+            filter_chore_deps: true,
+            skip_commit_patterns: Vec::new(),
+            release_commit_pattern: release_commit_regex(DEFAULT_RELEASE_COMMIT_TEMPLATE),
+            tag_prefix: "v".into(),
+            tag_format: None,
+            revert_prefix: DEFAULT_REVERT_PREFIX.into(),
+            hide_reverted: false,
+            on_empty_description: OnEmptyDescription::Summary,
+            migration_footer: DEFAULT_MIGRATION_FOOTER.into(),
+            category_footer: DEFAULT_CATEGORY_FOOTER.into(),
+            no_change_exit_code: crate::pipeline::ExitCode::NoChange as i32,
+            no_change_message: DEFAULT_NO_CHANGE_MESSAGE.into(),
+            release_name_template: None,
+            sources: EcoVec::new(),
+        }
+    }
 }
 
 pub fn default_types() -> Vec<TypeConfigResolved> {
@@ -128,13 +645,114 @@ pub fn default_types() -> Vec<TypeConfigResolved> {
         .map(|(k, t, e, s)| TypeConfigResolved {
             key: (*k).into(),
             title: (*t).into(),
+            singular_title: None,
             emoji: (*e).into(),
             semver: *s,
             enabled: true,
+            link_refs: true,
         })
         .collect()
 }
 
+/// Heading for a custom type configured without a title: `hotfix` becomes
+/// "Hotfix", `security-fix` "Security Fix".
+fn title_case(key: &str) -> EcoString {
+    let words: Vec<String> = key
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+    if words.is_empty() {
+        return key.into();
+    }
+    words.join(" ").into()
+}
+
+/// `{{token}}` placeholders in a release commit template.
+static TEMPLATE_TOKEN: once_cell::sync::Lazy<Option<regex::Regex>> =
+    once_cell::sync::Lazy::new(|| regex::Regex::new(r"\{\{\s*([A-Za-z.]+)\s*\}\}").ok());
+
+/// Compile a release commit template into a regex matching whole summaries.
+///
+/// `{{newVersion}}` matches any version and other `{{...}}` tokens match any
+/// text; everything else is literal. An empty template gives None.
+fn release_commit_regex(template: &str) -> Option<regex::Regex> {
+    let template = template.trim();
+    if template.is_empty() {
+        return None;
+    }
+    let token = TEMPLATE_TOKEN.as_ref()?;
+    let mut pattern = String::from("^");
+    let mut last = 0;
+    for caps in token.captures_iter(template) {
+        let m = caps.get(0)?;
+        pattern.push_str(&regex::escape(&template[last..m.start()]));
+        pattern.push_str(if &caps[1] == "newVersion" {
+            r"\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?"
+        } else {
+            ".+?"
+        });
+        last = m.end();
+    }
+    pattern.push_str(&regex::escape(&template[last..]));
+    pattern.push('$');
+    regex::Regex::new(&pattern).ok()
+}
+
+/// Starter `novalyn.toml` written by `novalyn init`.
+///
+/// Spells out every type from [`default_types`], so the file starts from the
+/// built-in defaults, followed by commented examples of common settings.
+pub fn starter_config() -> String {
+    use std::fmt::Write;
+    let mut out = String::from(
+        r#"# novalyn configuration
+#
+# Settings here are overridden by [package.metadata.novalyn] in Cargo.toml and
+# by command-line flags. Anything left out falls back to the built-in default.
+
+# Hide author emails in the contributors section.
+# hide_author_email = true
+
+# Shared author policy: a TOML file with an `exclude` list of names or emails
+# (e.g. exclude = ["renovate[bot]", "bot@example.com"]) and an `[aliases]` table
+# mapping old identities to new ones. `--exclude-author` adds to the list.
+# authors_file = "authors.toml"
+
+# Commit types, in changelog section order. `semver` is "major", "minor",
+# "patch" or "none"; set a type to false (e.g. `chore = false` under [types])
+# to leave it out of the changelog.
+"#,
+    );
+    for ty in default_types() {
+        let _ = write!(
+            out,
+            "\n[types.{}]\ntitle = {}\nemoji = {}\nsemver = \"{}\"\n",
+            ty.key,
+            toml_edit::Value::from(ty.title.as_str()),
+            toml_edit::Value::from(ty.emoji.as_str()),
+            ty.semver.as_str()
+        );
+    }
+    out.push_str(
+        r#"
+# Rename scopes in entries. Keys prefixed with "re:" are regular expressions
+# whose match is replaced; an empty value drops the scope.
+# [scope_map]
+# deps = "dependencies"
+# "re:^api-.*" = "api"
+# internal = ""
+"#,
+    );
+    out
+}
+
 pub struct LoadOptions<'a> {
     /// Working directory to search for config files
     pub cwd: &'a Path,
@@ -150,6 +768,9 @@ pub struct LoadOptions<'a> {
 /// 3. novalyn.toml
 /// 4. Built-in defaults
 ///
+/// The Cargo.toml layer is skipped when novalyn.toml or the CLI overrides set
+/// `no_cargo_metadata`.
+///
 /// # Arguments
 /// * `opts` - Load options specifying paths and overrides
 ///
@@ -159,11 +780,103 @@ pub struct LoadOptions<'a> {
 pub async fn load_config_async(opts: LoadOptions<'_>) -> Result<ResolvedConfig> {
     let mut warnings = EcoVec::new();
     let mut source_file = None;
-    let mut raw_stack: Vec<RawConfig> = Vec::new();
+    let mut raw_stack: Vec<(EcoString, RawConfig)> = Vec::new();
+
+    load_dir_layers_async(
+        opts.cwd,
+        true,
+        no_metadata_override(opts.cli_overrides.as_ref()),
+        &mut raw_stack,
+        &mut warnings,
+        &mut source_file,
+    )
+    .await;
+
+    // 3. CLI overrides last
+    if let Some(cli) = opts.cli_overrides {
+        raw_stack.push(("CLI overrides".into(), cli));
+    }
+
+    // Call common merge logic
+    merge_and_resolve_config(opts.cwd, raw_stack, warnings, source_file)
+}
 
+/// Load configuration for a workspace member asynchronously.
+///
+/// Layers found in the workspace `root` apply first and are overridden by the
+/// member's own novalyn.toml / Cargo.toml metadata, then by CLI overrides.
+pub async fn load_member_config_async(
+    root: &Path,
+    member: &Path,
+    cli_overrides: Option<RawConfig>,
+) -> Result<ResolvedConfig> {
+    let mut warnings = EcoVec::new();
+    let mut source_file = None;
+    let mut raw_stack: Vec<(EcoString, RawConfig)> = Vec::new();
+    let no_metadata = no_metadata_override(cli_overrides.as_ref());
+    load_dir_layers_async(
+        root,
+        false,
+        no_metadata,
+        &mut raw_stack,
+        &mut warnings,
+        &mut source_file,
+    )
+    .await;
+    if member != root {
+        load_dir_layers_async(
+            member,
+            false,
+            no_metadata,
+            &mut raw_stack,
+            &mut warnings,
+            &mut source_file,
+        )
+        .await;
+    }
+    if let Some(cli) = cli_overrides {
+        raw_stack.push(("CLI overrides".into(), cli));
+    }
+    merge_and_resolve_config(member, raw_stack, warnings, source_file)
+}
+
+/// `no_cargo_metadata` as set by CLI overrides, which wins over config files.
+fn no_metadata_override(cli_overrides: Option<&RawConfig>) -> Option<bool> {
+    cli_overrides.and_then(|cli| cli.no_cargo_metadata)
+}
+
+/// Whether the Cargo.toml metadata layer is skipped, given the layers loaded so far.
+fn skips_metadata(no_metadata: Option<bool>, raw_stack: &[(EcoString, RawConfig)]) -> bool {
+    no_metadata
+        .or_else(|| {
+            raw_stack
+                .iter()
+                .rev()
+                .find_map(|(_, r)| r.no_cargo_metadata)
+        })
+        .unwrap_or(false)
+}
+
+/// Push the config layers found in `dir` (novalyn.toml, then Cargo.toml metadata).
+///
+/// With `ascend`, novalyn.toml may also come from a parent directory inside the
+/// same repository. The metadata layer is left out when `no_metadata` (the CLI
+/// value) or an earlier layer sets `no_cargo_metadata`.
+async fn load_dir_layers_async(
+    dir: &Path,
+    ascend: bool,
+    no_metadata: Option<bool>,
+    raw_stack: &mut Vec<(EcoString, RawConfig)>,
+    warnings: &mut EcoVec<EcoString>,
+    source_file: &mut Option<PathBuf>,
+) {
     // Load config files concurrently using join! for parallel I/O
-    let novalyn_toml_path = find_file(opts.cwd, "novalyn.toml");
-    let cargo_toml_path = find_file(opts.cwd, "Cargo.toml");
+    let novalyn_toml_path = if ascend {
+        find_file_upwards(dir, "novalyn.toml")
+    } else {
+        find_file(dir, "novalyn.toml")
+    };
+    let cargo_toml_path = find_file(dir, "Cargo.toml");
 
     // Load both files concurrently if they exist
     let (novalyn_result, cargo_result) = tokio::join!(
@@ -187,36 +900,32 @@ pub async fn load_config_async(opts: LoadOptions<'_>) -> Result<ResolvedConfig>
     if let Some(result) = novalyn_result {
         match result {
             Ok(rc) => {
-                source_file = Some(novalyn_toml_path.unwrap());
-                raw_stack.push(rc);
+                let path = novalyn_toml_path.unwrap();
+                raw_stack.push((format!("novalyn.toml ({})", path.display()).into(), rc));
+                *source_file = Some(path);
             }
             Err(e) => {
-                warnings.push(format!("Failed loading novalyn.toml: {e}").into());
+                warnings.push(format!("Failed to load novalyn.toml: {e}").into());
             }
         }
     }
 
     // 2. Cargo.toml [package.metadata.novalyn]
-    if let Some(result) = cargo_result {
+    if skips_metadata(no_metadata, raw_stack) {
+        return;
+    }
+    if let (Some(result), Some(path)) = (cargo_result, &cargo_toml_path) {
         match result {
             Ok(s) => {
-                if let Some(rc) = extract_metadata_block(&s, &mut warnings) {
-                    raw_stack.push(rc);
+                if let Some(rc) = extract_metadata_block(&s, warnings) {
+                    raw_stack.push((metadata_source(path), rc));
                 }
             }
             Err(e) => {
-                warnings.push(format!("Failed loading Cargo.toml: {e}").into());
+                warnings.push(format!("Failed to read Cargo.toml: {e}").into());
             }
         }
     }
-
-    // 3. CLI overrides last
-    if let Some(cli) = opts.cli_overrides {
-        raw_stack.push(cli);
-    }
-
-    // Call common merge logic
-    merge_and_resolve_config(opts.cwd, raw_stack, warnings, source_file)
 }
 
 /// Merge and resolve configuration from raw config stack.
@@ -224,10 +933,13 @@ pub async fn load_config_async(opts: LoadOptions<'_>) -> Result<ResolvedConfig>
 /// This is the common logic used by both sync and async config loaders.
 fn merge_and_resolve_config(
     cwd: &Path,
-    raw_stack: Vec<RawConfig>,
+    layers: Vec<(EcoString, RawConfig)>,
     mut warnings: EcoVec<EcoString>,
     source_file: Option<PathBuf>,
 ) -> Result<ResolvedConfig> {
+    let (source_names, raw_stack): (Vec<EcoString>, Vec<RawConfig>) = layers.into_iter().unzip();
+    let sources = config_sources(source_names, &raw_stack);
+
     // Merge stack in order added (file(s) then CLI). Defaults applied separately.
     let mut types = default_types();
 
@@ -246,10 +958,12 @@ fn merge_and_resolve_config(
                                 // create disabled placeholder so later override could re-enable
                                 types.push(TypeConfigResolved {
                                     key: k.clone(),
-                                    title: k.clone(),
+                                    title: title_case(k),
+                                    singular_title: None,
                                     emoji: EcoString::new(),
                                     semver: SemverImpact::None,
                                     enabled: false,
+                                    link_refs: true,
                                 });
                             }
                         }
@@ -267,18 +981,26 @@ fn merge_and_resolve_config(
                             if let Some(title) = &part.title {
                                 t.title = title.clone();
                             }
+                            if let Some(singular) = &part.singular_title {
+                                t.singular_title = Some(singular.clone());
+                            }
                             if let Some(emoji) = &part.emoji {
                                 t.emoji = emoji.clone();
                             }
+                            if let Some(link_refs) = part.link_refs {
+                                t.link_refs = link_refs;
+                            }
                             t.semver = semver;
                             t.enabled = true;
                         } else {
                             types.push(TypeConfigResolved {
                                 key: k.clone(),
-                                title: part.title.clone().unwrap_or_else(|| k.clone()),
+                                title: part.title.clone().unwrap_or_else(|| title_case(k)),
+                                singular_title: part.singular_title.clone(),
                                 emoji: part.emoji.clone().unwrap_or_default(),
                                 semver,
                                 enabled: true,
+                                link_refs: part.link_refs.unwrap_or(true),
                             });
                         }
                     }
@@ -308,7 +1030,27 @@ fn merge_and_resolve_config(
     }
 
     // Attempt repository detection (non-fatal)
-    let repo = detect_repository(cwd, &mut warnings);
+    let mut repo = detect_repository(cwd, &mut warnings);
+    let explicit_api_url = std::env::var("NOVALYN_GITHUB_API")
+        .ok()
+        .filter(|v| !v.is_empty())
+        .map(EcoString::from)
+        .or_else(|| last_set(&raw_stack, |r| r.github.as_ref()?.api_url.clone()));
+    // A remote on the host of a configured API is a GitHub Enterprise repository
+    if let (Some(api_url), Some(r)) = (&explicit_api_url, &mut repo)
+        && r.provider == repo_mod::Provider::Other
+        && url_host(api_url) == Some(r.host.as_str())
+    {
+        r.provider = repo_mod::Provider::GitHub;
+    }
+    let github_api_url = explicit_api_url
+        .map(|url| url.trim_end_matches('/').into())
+        .unwrap_or_else(|| match &repo {
+            Some(r) if r.provider == repo_mod::Provider::GitHub && r.host != "github.com" => {
+                format!("https://{}/api/v3", r.host).into()
+            }
+            _ => crate::github::DEFAULT_API_BASE.into(),
+        });
 
     // Merge scope_map layering later entries override earlier
     let mut scope_map: BTreeMap<EcoString, EcoString> = BTreeMap::new();
@@ -319,19 +1061,262 @@ fn merge_and_resolve_config(
             }
         }
     }
+    // Split off regex entries ("re:<pattern>"), compiled in BTreeMap order
+    let mut scope_patterns = Vec::new();
+    scope_map.retain(|k, v| {
+        let Some(pattern) = k.strip_prefix(SCOPE_REGEX_PREFIX) else {
+            return true;
+        };
+        match regex::Regex::new(pattern) {
+            Ok(re) => scope_patterns.push((re, v.clone())),
+            Err(e) => warnings.push(format!("Invalid scope_map regex '{pattern}': {e}").into()),
+        }
+        false
+    });
+
+    let fetch_pr_labels = last_set(&raw_stack, |r| r.fetch_pr_labels).unwrap_or(false);
+    let compare_short_sha = last_set(&raw_stack, |r| r.compare_short_sha).unwrap_or(true);
+    let compare_link = last_set(&raw_stack, |r| r.compare_link).unwrap_or(true);
+    let show_range = last_set(&raw_stack, |r| r.show_range).unwrap_or(false);
+    let show_type_label = last_set(&raw_stack, |r| r.show_type_label).unwrap_or(false);
+    let emoji = last_set(&raw_stack, |r| r.emoji).unwrap_or(true);
+    let banned_scopes = last_set(&raw_stack, |r| r.banned_scopes.clone()).unwrap_or_default();
+    let pre_release_hooks =
+        last_set(&raw_stack, |r| r.pre_release_hooks.clone()).unwrap_or_default();
+    let post_release_hooks =
+        last_set(&raw_stack, |r| r.post_release_hooks.clone()).unwrap_or_default();
+    let breaking_section_title = last_set(&raw_stack, |r| r.breaking_section_title.clone())
+        .unwrap_or(DEFAULT_BREAKING_SECTION_TITLE.into());
+    let group_breaking = last_set(&raw_stack, |r| r.group_breaking).unwrap_or(false);
+    let dedupe_breaking = last_set(&raw_stack, |r| r.dedupe_breaking).unwrap_or(false);
+    let section_sort = last_set(&raw_stack, |r| r.section_sort).unwrap_or_default();
+    let max_refs_per_entry = last_set(&raw_stack, |r| r.max_refs_per_entry);
+    let show_closed_issues = last_set(&raw_stack, |r| r.show_closed_issues).unwrap_or(false);
+    let collapse_threshold = last_set(&raw_stack, |r| r.collapse_threshold);
+    let render_footers = last_set(&raw_stack, |r| r.render_footers.clone()).unwrap_or_default();
+    let commit_template =
+        last_set(&raw_stack, |r| r.commit_template.clone()).filter(|t| !t.trim().is_empty());
+    let co_author_keys = last_set(&raw_stack, |r| r.co_author_keys.clone())
+        .unwrap_or_else(|| EcoVec::from([EcoString::from(DEFAULT_CO_AUTHOR_KEY)]));
+    let expand_body_bullets = last_set(&raw_stack, |r| r.expand_body_bullets).unwrap_or(false);
+    let link_commits = last_set(&raw_stack, |r| r.link_commits).unwrap_or(false);
+    let new_contributors = last_set(&raw_stack, |r| r.new_contributors).unwrap_or(false);
+    let contributor_groups = last_set(&raw_stack, |r| r.contributor_groups.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(domain, label)| (domain.to_lowercase(), label))
+        .collect();
+    let exclude_paths = last_set(&raw_stack, |r| r.exclude_paths.clone()).unwrap_or_default();
+    let strict_conventional = last_set(&raw_stack, |r| r.strict_conventional).unwrap_or(false);
+    let keyword_types = last_set(&raw_stack, |r| r.keyword_types.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(keyword, ty)| (keyword.to_lowercase(), ty))
+        .collect();
+    let type_aliases = last_set(&raw_stack, |r| r.type_aliases.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(alias, ty)| (alias.to_lowercase(), ty))
+        .collect();
+    let slash_scope = last_set(&raw_stack, |r| r.slash_scope).unwrap_or(false);
+    let intro = last_set(&raw_stack, |r| r.intro.clone());
+    let outro = last_set(&raw_stack, |r| r.outro.clone());
+    let previous_tag_strategy =
+        last_set(&raw_stack, |r| r.previous_tag_strategy).unwrap_or_default();
+    let manifest_version_fallback =
+        last_set(&raw_stack, |r| r.manifest_version_fallback).unwrap_or(true);
+    let zero_major_feat = last_set(&raw_stack, |r| r.zero_major_feat).unwrap_or_default();
+    let min_bump = last_set(&raw_stack, |r| r.min_bump).unwrap_or_default();
+    let prerelease_format = last_set(&raw_stack, |r| r.prerelease_format.clone()).filter(|f| {
+        let valid = valid_prerelease_format(f);
+        if !valid {
+            warnings.push(
+                format!("prerelease_format '{f}' is not a valid prerelease identifier; ignoring")
+                    .into(),
+            );
+        }
+        valid
+    });
+    let authors_file = last_set(&raw_stack, |r| r.authors_file.clone());
+    let skip_marker =
+        last_set(&raw_stack, |r| r.skip_marker.clone()).unwrap_or(DEFAULT_SKIP_MARKER.into());
+    let filter_chore_deps = last_set(&raw_stack, |r| r.filter_chore_deps).unwrap_or(true);
+    let skip_commit_patterns = last_set(&raw_stack, |r| r.skip_commit_patterns.clone())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|pattern| match regex::Regex::new(pattern) {
+            Ok(re) => Some(re),
+            Err(e) => {
+                warnings
+                    .push(format!("Invalid skip_commit_patterns regex '{pattern}': {e}").into());
+                None
+            }
+        })
+        .collect();
+    let release_commit_pattern = release_commit_regex(
+        &last_set(&raw_stack, |r| r.release_commit_template.clone())
+            .unwrap_or(DEFAULT_RELEASE_COMMIT_TEMPLATE.into()),
+    );
+    let tag_prefix = last_set(&raw_stack, |r| r.tag_prefix.clone()).unwrap_or("v".into());
+    let crate_name = crate_name(cwd);
+    let mut tag_format = last_set(&raw_stack, |r| r.tag_format.clone());
+    if let Some(format) = &mut tag_format
+        && format.contains(CRATE_NAME_TOKEN)
+    {
+        if crate_name.is_none() {
+            warnings.push(
+                format!(
+                    "tag_format '{format}' uses {CRATE_NAME_TOKEN} but no crate name was found"
+                )
+                .into(),
+            );
+        }
+        *format = format.replace(CRATE_NAME_TOKEN, crate_name.as_deref().unwrap_or_default());
+    }
+    let revert_prefix =
+        last_set(&raw_stack, |r| r.revert_prefix.clone()).unwrap_or(DEFAULT_REVERT_PREFIX.into());
+    let hide_reverted = last_set(&raw_stack, |r| r.hide_reverted).unwrap_or(false);
+    let on_empty_description = last_set(&raw_stack, |r| r.on_empty_description).unwrap_or_default();
+    let migration_footer = last_set(&raw_stack, |r| r.migration_footer.clone())
+        .unwrap_or(DEFAULT_MIGRATION_FOOTER.into());
+    let category_footer = last_set(&raw_stack, |r| r.category_footer.clone())
+        .unwrap_or(DEFAULT_CATEGORY_FOOTER.into());
+    let no_change_exit_code = last_set(&raw_stack, |r| r.no_change_exit_code)
+        .unwrap_or(crate::pipeline::ExitCode::NoChange as i32);
+    let no_change_message = last_set(&raw_stack, |r| r.no_change_message.clone())
+        .unwrap_or(DEFAULT_NO_CHANGE_MESSAGE.into());
+    let release_name_template = last_set(&raw_stack, |r| r.release_name_template.clone());
+    if let Some(format) = &tag_format
+        && !format.contains(TAG_VERSION_TOKEN)
+    {
+        warnings.push(format!("tag_format '{format}' lacks the {TAG_VERSION_TOKEN} token").into());
+    }
 
     Ok(ResolvedConfig {
         types,
         new_version,
         warnings,
         github_token,
+        github_api_url,
         cwd: cwd.to_path_buf(),
         source_file,
+        crate_name,
         repo,
         scope_map,
+        scope_patterns,
+        fetch_pr_labels,
+        compare_short_sha,
+        compare_link,
+        show_range,
+        show_type_label,
+        emoji,
+        banned_scopes,
+        pre_release_hooks,
+        post_release_hooks,
+        breaking_section_title,
+        group_breaking,
+        dedupe_breaking,
+        section_sort,
+        max_refs_per_entry,
+        show_closed_issues,
+        collapse_threshold,
+        render_footers,
+        commit_template,
+        co_author_keys,
+        expand_body_bullets,
+        link_commits,
+        new_contributors,
+        contributor_groups,
+        exclude_paths,
+        strict_conventional,
+        keyword_types,
+        type_aliases,
+        slash_scope,
+        intro,
+        outro,
+        previous_tag_strategy,
+        manifest_version_fallback,
+        zero_major_feat,
+        min_bump,
+        prerelease_format,
+        authors_file,
+        skip_marker,
+        filter_chore_deps,
+        skip_commit_patterns,
+        release_commit_pattern,
+        tag_prefix,
+        tag_format,
+        revert_prefix,
+        hide_reverted,
+        on_empty_description,
+        migration_footer,
+        category_footer,
+        no_change_exit_code,
+        no_change_message,
+        release_name_template,
+        sources,
     })
 }
 
+/// Label for the `[package.metadata.novalyn]` layer of a Cargo.toml.
+fn metadata_source(path: &Path) -> EcoString {
+    format!("Cargo.toml [package.metadata.novalyn] ({})", path.display()).into()
+}
+
+/// Keys set by a layer, with map entries as `types.feat` / `scope_map.api`.
+fn layer_keys(raw: &RawConfig) -> Vec<EcoString> {
+    let Ok(serde_json::Value::Object(map)) = serde_json::to_value(raw) else {
+        return Vec::new();
+    };
+    let mut keys = Vec::new();
+    for (key, value) in map {
+        if raw._unknown.contains_key(&key) {
+            continue;
+        }
+        match value {
+            serde_json::Value::Null => {}
+            serde_json::Value::Object(entries) if key == "types" || key == "scope_map" => {
+                keys.extend(entries.keys().map(|k| format!("{key}.{k}").into()));
+            }
+            _ => keys.push(key.into()),
+        }
+    }
+    keys
+}
+
+/// Record which keys each layer contributed after merging.
+///
+/// A key counts for the highest-precedence layer setting it; keys also set
+/// by a later layer are reported as overridden.
+fn config_sources(names: Vec<EcoString>, raw_stack: &[RawConfig]) -> EcoVec<ConfigSource> {
+    let keys: Vec<Vec<EcoString>> = raw_stack.iter().map(layer_keys).collect();
+    names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let (overridden, keys): (Vec<EcoString>, Vec<EcoString>) = keys[i]
+                .iter()
+                .cloned()
+                .partition(|k| keys[i + 1..].iter().any(|later| later.contains(k)));
+            ConfigSource {
+                name,
+                keys: keys.into(),
+                overridden: overridden.into(),
+            }
+        })
+        .collect()
+}
+
+/// Whether `format` expands to a valid semver prerelease identifier.
+pub fn valid_prerelease_format(format: &str) -> bool {
+    crate::parse::prerelease_identifier(&format.replace("{{date}}", "2000-01-01"), 1).is_some()
+}
+
+/// Return the value set by the highest-precedence layer, if any layer sets it.
+fn last_set<T>(raw_stack: &[RawConfig], get: impl Fn(&RawConfig) -> Option<T>) -> Option<T> {
+    raw_stack.iter().rev().find_map(get)
+}
+
 /// Load and merge configuration from multiple sources synchronously.
 ///
 /// Configuration precedence (highest to lowest):
@@ -340,6 +1325,9 @@ fn merge_and_resolve_config(
 /// 3. novalyn.toml
 /// 4. Built-in defaults
 ///
+/// The Cargo.toml layer is skipped when novalyn.toml or the CLI overrides set
+/// `no_cargo_metadata`.
+///
 /// # Arguments
 /// * `opts` - Load options specifying paths and overrides
 ///
@@ -349,26 +1337,29 @@ fn merge_and_resolve_config(
 pub fn load_config(opts: LoadOptions) -> Result<ResolvedConfig> {
     let mut warnings = EcoVec::new();
     let mut source_file = None;
-    let mut raw_stack: Vec<RawConfig> = Vec::new();
+    let mut raw_stack: Vec<(EcoString, RawConfig)> = Vec::new();
 
     // defaults placeholder (empty RawConfig means rely on default types below)
     // 1. novalyn.toml
-    if let Some(path) = find_file(opts.cwd, "novalyn.toml") {
+    if let Some(path) = find_file_upwards(opts.cwd, "novalyn.toml") {
         match load_file(&path) {
             Ok(rc) => {
-                source_file = Some(path.clone());
-                raw_stack.push(rc);
+                raw_stack.push((format!("novalyn.toml ({})", path.display()).into(), rc));
+                source_file = Some(path);
             }
             Err(e) => warnings.push(format!("Failed to load novalyn.toml: {e}").into()),
         }
     }
 
     // 2. Cargo.toml [package.metadata.novalyn]
-    if let Some(cargo_path) = find_file(opts.cwd, "Cargo.toml") {
+    let no_metadata = no_metadata_override(opts.cli_overrides.as_ref());
+    if !skips_metadata(no_metadata, &raw_stack)
+        && let Some(cargo_path) = find_file(opts.cwd, "Cargo.toml")
+    {
         match fs::read_to_string(&cargo_path) {
             Ok(s) => {
                 if let Some(rc) = extract_metadata_block(&s, &mut warnings) {
-                    raw_stack.push(rc);
+                    raw_stack.push((metadata_source(&cargo_path), rc));
                 }
             }
             Err(e) => warnings.push(format!("Failed to read Cargo.toml: {e}").into()),
@@ -377,7 +1368,7 @@ pub fn load_config(opts: LoadOptions) -> Result<ResolvedConfig> {
 
     // 3. CLI overrides last
     if let Some(cli) = opts.cli_overrides {
-        raw_stack.push(cli);
+        raw_stack.push(("CLI overrides".into(), cli));
     }
 
     // Call common merge logic
@@ -431,6 +1422,80 @@ fn find_file(cwd: &Path, name: &str) -> Option<PathBuf> {
     }
 }
 
+/// Record `keyword -> type` pairs in the `[keyword_types]` table of the
+/// nearest novalyn.toml, creating `cwd/novalyn.toml` if there is none.
+///
+/// # Returns
+/// Path of the updated config file
+pub fn save_keyword_types(cwd: &Path, pairs: &[(EcoString, EcoString)]) -> Result<PathBuf> {
+    let path = find_file_upwards(cwd, "novalyn.toml").unwrap_or_else(|| cwd.join("novalyn.toml"));
+    let txt = match fs::read_to_string(&path) {
+        Ok(txt) => txt,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("Reading {path:?}")),
+    };
+    let mut doc: toml_edit::DocumentMut = txt
+        .parse()
+        .with_context(|| format!("Parsing TOML {path:?}"))?;
+    let table = doc
+        .entry("keyword_types")
+        .or_insert_with(toml_edit::table)
+        .as_table_mut()
+        .ok_or_else(|| anyhow::anyhow!("keyword_types in {path:?} is not a table"))?;
+    for (keyword, ty) in pairs {
+        table.insert(keyword, toml_edit::value(ty.as_str()));
+    }
+    fs::write(&path, doc.to_string()).with_context(|| format!("Writing {path:?}"))?;
+    Ok(path)
+}
+
+/// Read the package name from `cwd/Cargo.toml`.
+///
+/// # Returns
+/// `[package].name`, or None without a readable manifest or package table
+pub fn crate_name(cwd: &Path) -> Option<EcoString> {
+    let txt = fs::read_to_string(cwd.join("Cargo.toml")).ok()?;
+    let doc: toml_edit::DocumentMut = txt.parse().ok()?;
+    doc.get("package")?.get("name")?.as_str().map(Into::into)
+}
+
+/// Read the package version from `cwd/Cargo.toml`.
+///
+/// # Returns
+/// `[package].version`, or None when it is missing, inherited from the
+/// workspace or not valid semver
+pub fn crate_version(cwd: &Path) -> Option<Version> {
+    let txt = fs::read_to_string(cwd.join("Cargo.toml")).ok()?;
+    let doc: toml_edit::DocumentMut = txt.parse().ok()?;
+    Version::parse(doc.get("package")?.get("version")?.as_str()?).ok()
+}
+
+/// Find a configuration file in `cwd` or the nearest parent directory containing it.
+///
+/// The search stops at the root of the git work tree enclosing `cwd`, so a
+/// parent repository's config is never picked up; outside a repository it
+/// continues up to the filesystem root.
+///
+/// # Returns
+/// Full path of the first match, None otherwise
+fn find_file_upwards(cwd: &Path, name: &str) -> Option<PathBuf> {
+    let Ok(start) = cwd.canonicalize() else {
+        return find_file(cwd, name);
+    };
+    let boundary = gix::discover(&start)
+        .ok()
+        .and_then(|repo| repo.workdir().and_then(|w| w.canonicalize().ok()));
+    for dir in start.ancestors() {
+        if let Some(found) = find_file(dir, name) {
+            return Some(found);
+        }
+        if boundary.as_deref() == Some(dir) {
+            break;
+        }
+    }
+    None
+}
+
 /// Extract [package.metadata.novalyn] block from Cargo.toml.
 ///
 /// # Arguments
@@ -487,6 +1552,12 @@ fn resolve_github_token() -> Option<EcoString> {
     None
 }
 
+/// Host (with port) of an `http(s)://host/...` URL.
+fn url_host(url: &str) -> Option<&str> {
+    let rest = url.split_once("://")?.1;
+    Some(rest.split('/').next().unwrap_or(rest))
+}
+
 /// Log configuration warnings using the tracing framework.
 ///
 /// # Arguments
@@ -509,8 +1580,9 @@ pub fn log_warnings(cfg: &ResolvedConfig) {
 /// Repository information if detected, None otherwise
 fn detect_repository(cwd: &Path, warnings: &mut EcoVec<EcoString>) -> Option<repo_mod::Repository> {
     // crate path valid when used as library
-    // Open git repo; if not a git repository, silently return None (git layer will handle hard error later)
-    let repo = match gix::open(cwd) {
+    // Discover git repo from cwd upwards; if not inside a git repository, silently
+    // return None (git layer will handle hard error later)
+    let repo = match gix::discover(cwd) {
         Ok(r) => r,
         Err(_) => return None,
     };
@@ -5,6 +5,17 @@
 
 use crate::git::RawCommit;
 use ecow::{EcoString, EcoVec};
+use serde::Serialize;
+
+/// What marked a commit as breaking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakingSource {
+    /// `!` before the colon in the header (`feat!: ...`)
+    Marker,
+    /// A `BREAKING CHANGE:` footer
+    Footer,
+}
 
 /// Parsed commit fields ready for ParsedCommit construction
 pub struct ParsedFields {
@@ -14,8 +25,42 @@ pub struct ParsedFields {
     pub body: EcoString,
     pub footers: EcoVec<(EcoString, EcoString)>,
     pub breaking: bool,
+    /// Why the commit is breaking; the header marker wins over a footer
+    pub breaking_source: Option<BreakingSource>,
     pub issues: EcoVec<u64>,
+    /// Issues named after a closing keyword (`Closes #1`), a subset of `issues`
+    pub closed_issues: EcoVec<u64>,
+    /// GitLab merge requests referenced as `!123` (only with [`ParseOptions::merge_requests`])
+    pub merge_requests: EcoVec<u64>,
     pub co_authors: EcoVec<EcoString>,
+    pub pr: Option<u64>,
+    /// Whether the summary follows the `type(scope)!: description` header format
+    pub conventional: bool,
+    /// SHA from a `This reverts commit <sha>.` body line
+    pub reverts: Option<EcoString>,
+}
+
+/// Syntax extensions accepted by [`parse_commit_with`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseOptions<'a> {
+    /// Also accept `type/scope: description` alongside `type(scope): description`
+    pub slash_scope: bool,
+    /// Footer keys naming co-authors (empty means the standard `Co-authored-by`)
+    pub co_author_keys: &'a [EcoString],
+    /// Also collect GitLab-style `!123` merge-request references
+    pub merge_requests: bool,
+}
+
+impl ParseOptions<'_> {
+    /// Whether footer `token` names a co-author, ignoring ASCII case.
+    pub fn is_co_author_key(&self, token: &str) -> bool {
+        if self.co_author_keys.is_empty() {
+            return token.eq_ignore_ascii_case(crate::config::DEFAULT_CO_AUTHOR_KEY);
+        }
+        self.co_author_keys
+            .iter()
+            .any(|key| token.eq_ignore_ascii_case(key))
+    }
 }
 
 /// Parse a commit directly into the required fields for ParsedCommit
@@ -23,6 +68,12 @@ pub struct ParsedFields {
 /// This skips intermediate allocations and parses everything in one pass.
 #[inline]
 pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
+    parse_commit_with(rc, &ParseOptions::default())
+}
+
+/// Parse a commit like [`parse_commit_fast`], honouring header extensions.
+#[inline]
+pub fn parse_commit_with(rc: &RawCommit, opts: &ParseOptions<'_>) -> ParsedFields {
     let bytes = rc.summary.as_bytes();
     let mut pos = 0;
 
@@ -32,7 +83,8 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
         pos += 1;
     }
 
-    let r#type: EcoString = if pos > type_start {
+    let mut has_type = pos > type_start;
+    let mut r#type: EcoString = if has_type {
         rc.summary[type_start..pos].to_ascii_lowercase().into()
     } else {
         "other".into()
@@ -41,14 +93,24 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
     // Check for scope
     let scope = if pos < bytes.len() && bytes[pos] == b'(' {
         pos += 1; // skip '('
-        if let Some(offset) = memchr::memchr(b')', &bytes[pos..]) {
-            let scope_end = pos + offset;
-            let scope_text: EcoString = rc.summary[pos..scope_end].into();
-            pos = scope_end + 1; // skip ')'
-            Some(scope_text)
-        } else {
-            None
+        match memchr::memchr(b')', &bytes[pos..]) {
+            Some(offset) if memchr::memchr(b':', &bytes[pos..pos + offset]).is_none() => {
+                let scope_end = pos + offset;
+                let scope_text: EcoString = rc.summary[pos..scope_end].into();
+                pos = scope_end + 1; // skip ')'
+                Some(scope_text)
+            }
+            _ => {
+                // Unterminated scope (`feat(api: thing`): not a conventional
+                // header, so keep the whole summary as the description
+                has_type = false;
+                r#type = "other".into();
+                pos = 0;
+                None
+            }
         }
+    } else if opts.slash_scope && has_type && pos < bytes.len() && bytes[pos] == b'/' {
+        parse_slash_scope(&rc.summary, &mut pos)
     } else {
         None
     };
@@ -60,9 +122,16 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
     } else {
         false
     };
+    let marker = breaking;
+    let source = |breaking: bool| match (marker, breaking) {
+        (true, _) => Some(BreakingSource::Marker),
+        (false, true) => Some(BreakingSource::Footer),
+        (false, false) => None,
+    };
 
     // Expect ':'
-    if pos < bytes.len() && bytes[pos] == b':' {
+    let has_colon = pos < bytes.len() && bytes[pos] == b':';
+    if has_colon {
         pos += 1;
     }
 
@@ -73,6 +142,28 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
 
     // Trim all whitespace from both ends (handles edge cases like vertical tabs)
     let description: EcoString = rc.summary[pos..].trim().into();
+    let pr = extract_pr_number(&rc.summary);
+    let reverts = extract_reverted_commit(&rc.body);
+    // The header's type is left out so `fix: #12 ...` doesn't count as closing
+    let mut closed_issues: Vec<u64> = extract_closed_issues(&description)
+        .into_iter()
+        .chain(extract_closed_issues(&rc.body))
+        .collect();
+    closed_issues.sort_unstable();
+    closed_issues.dedup();
+    let closed_issues: EcoVec<u64> = closed_issues.into();
+    let merge_requests: EcoVec<u64> = if opts.merge_requests {
+        let mut mrs: Vec<u64> = extract_merge_requests(&rc.summary)
+            .into_iter()
+            .chain(extract_merge_requests(&rc.body))
+            .collect();
+        mrs.sort_unstable();
+        mrs.dedup();
+        mrs.into()
+    } else {
+        EcoVec::new()
+    };
+    let conventional = has_type && has_colon && !description.is_empty();
 
     // Fast path: no body means no footers
     if rc.body.is_empty() {
@@ -84,8 +175,14 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
             body: EcoString::new(),
             footers: EcoVec::new(),
             breaking,
+            breaking_source: source(breaking),
             issues,
+            closed_issues,
+            merge_requests,
             co_authors: EcoVec::new(),
+            pr,
+            conventional,
+            reverts,
         };
     }
 
@@ -102,55 +199,32 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
             body: EcoString::new(),
             footers: EcoVec::new(),
             breaking,
+            breaking_source: source(breaking),
             issues,
+            closed_issues,
+            merge_requests,
             co_authors: EcoVec::new(),
+            pr,
+            conventional,
+            reverts,
         };
     }
 
-    // Find footer boundary by scanning backwards
+    // Find footer boundary by scanning backwards: footers are the trailing
+    // run of footer lines after a blank line
     let mut footer_start_idx = None;
     for i in (0..lines.len()).rev() {
         if lines[i].trim().is_empty() {
-            // Check if everything after this looks like footers
-            // (footers or continuation lines)
-            let mut all_footers = true;
-            for &line in &lines[i + 1..] {
-                let trimmed = line.trim();
-                if !trimmed.is_empty() {
-                    // Check if it's a continuation line (starts with whitespace)
-                    if line.starts_with(' ') || line.starts_with('\t') {
-                        continue; // continuation line is valid
-                    }
-
-                    // Otherwise must be a footer with a colon
-                    if let Some(colon_pos) = memchr::memchr(b':', trimmed.as_bytes()) {
-                        if colon_pos == 0 || !is_valid_footer_token(&trimmed[..colon_pos]) {
-                            all_footers = false;
-                            break;
-                        }
-                    } else {
-                        all_footers = false;
-                        break;
-                    }
-                }
-            }
-
-            if all_footers && i + 1 < lines.len() {
+            if i + 1 < lines.len() && is_footer_block(&lines[i + 1..]) {
                 footer_start_idx = Some(i + 1);
-                break;
             }
+            break;
         }
     }
 
-    // Check if entire body is footers
-    if footer_start_idx.is_none() && !lines.is_empty() {
-        let first_trimmed = lines[0].trim();
-        if let Some(colon_pos) = memchr::memchr(b':', first_trimmed.as_bytes())
-            && colon_pos > 0
-            && is_valid_footer_token(&first_trimmed[..colon_pos])
-        {
-            footer_start_idx = Some(0);
-        }
+    // Check if entire body is footers (no body text before them)
+    if footer_start_idx.is_none() && is_footer_block(&lines) {
+        footer_start_idx = Some(0);
     }
 
     let footer_start_idx = match footer_start_idx {
@@ -173,8 +247,14 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
                 body: body_str.trim().into(),
                 footers: EcoVec::new(),
                 breaking,
+                breaking_source: source(breaking),
                 issues,
+                closed_issues,
+                merge_requests,
                 co_authors: EcoVec::new(),
+                pr,
+                conventional,
+                reverts,
             };
         }
     };
@@ -220,16 +300,12 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
                 // Save previous footer
                 if let Some(tok) = current_token.take() {
                     // Check for breaking change
-                    if !breaking
-                        && (tok.eq_ignore_ascii_case("BREAKING CHANGE")
-                            || tok.eq_ignore_ascii_case("BREAKING-CHANGE")
-                            || tok.eq_ignore_ascii_case("BREAKING CHANGES"))
-                    {
+                    if !breaking && is_breaking_footer(&tok) {
                         breaking = true;
                     }
 
                     // Check for co-author
-                    if tok.eq_ignore_ascii_case("Co-authored-by") {
+                    if opts.is_co_author_key(&tok) {
                         co_authors.push(current_value.clone());
                     }
 
@@ -255,15 +331,11 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
 
     // Save last footer
     if let Some(tok) = current_token {
-        if !breaking
-            && (tok.eq_ignore_ascii_case("BREAKING CHANGE")
-                || tok.eq_ignore_ascii_case("BREAKING-CHANGE")
-                || tok.eq_ignore_ascii_case("BREAKING CHANGES"))
-        {
+        if !breaking && is_breaking_footer(&tok) {
             breaking = true;
         }
 
-        if tok.eq_ignore_ascii_case("Co-authored-by") {
+        if opts.is_co_author_key(&tok) {
             co_authors.push(current_value.clone());
         }
 
@@ -293,9 +365,121 @@ pub fn parse_commit_fast(rc: &RawCommit) -> ParsedFields {
         body,
         footers,
         breaking,
+        breaking_source: source(breaking),
         issues,
+        closed_issues,
+        merge_requests,
         co_authors,
+        pr,
+        conventional,
+        reverts,
+    }
+}
+
+/// Whether a footer token marks a breaking change (`BREAKING CHANGE` and variants).
+pub fn is_breaking_footer(token: &str) -> bool {
+    token.eq_ignore_ascii_case("BREAKING CHANGE")
+        || token.eq_ignore_ascii_case("BREAKING-CHANGE")
+        || token.eq_ignore_ascii_case("BREAKING CHANGES")
+}
+
+/// Whether every non-blank line is a footer (`Token: value`) or a continuation
+/// line of one, with at least one footer present.
+fn is_footer_block(lines: &[&str]) -> bool {
+    let mut any = false;
+    for &line in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || line.starts_with(' ') || line.starts_with('\t') {
+            continue;
+        }
+        match memchr::memchr(b':', trimmed.as_bytes()) {
+            Some(colon_pos) if colon_pos > 0 && is_valid_footer_token(&trimmed[..colon_pos]) => {
+                any = true;
+            }
+            _ => return false,
+        }
+    }
+    any
+}
+
+/// Extract the pull request number from a squash-merge summary like `feat: thing (#123)`.
+#[inline]
+fn extract_pr_number(summary: &str) -> Option<u64> {
+    let inner = summary.trim_end().strip_suffix(')')?;
+    let start = memchr::memrchr(b'(', inner.as_bytes())?;
+    inner[start + 1..].strip_prefix('#')?.parse().ok()
+}
+
+/// The commit named by git's `This reverts commit <sha>.` line, if any.
+fn extract_reverted_commit(body: &str) -> Option<EcoString> {
+    body.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("This reverts commit ")?;
+        let end = rest
+            .find(|ch: char| !ch.is_ascii_hexdigit())
+            .unwrap_or(rest.len());
+        (end >= 7).then(|| rest[..end].into())
+    })
+}
+
+/// Issue numbers following a closing keyword, e.g. `Closes #1, #2` or
+/// `fixes: #3`, matched case-insensitively.
+fn extract_closed_issues(text: &str) -> EcoVec<u64> {
+    const KEYWORDS: [&str; 9] = [
+        "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+    ];
+    let bytes = text.as_bytes();
+    let mut closed = EcoVec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if !bytes[pos].is_ascii_alphabetic() {
+            pos += 1;
+            continue;
+        }
+        let start = pos;
+        while pos < bytes.len() && bytes[pos].is_ascii_alphabetic() {
+            pos += 1;
+        }
+        let word_start = start == 0 || !bytes[start - 1].is_ascii_alphanumeric();
+        let word = &text[start..pos];
+        if !word_start || !KEYWORDS.iter().any(|k| word.eq_ignore_ascii_case(k)) {
+            continue;
+        }
+        if bytes.get(pos) == Some(&b':') {
+            pos += 1;
+        }
+        // `#1`, `#1, #2`, `#1 and #2`, ...
+        loop {
+            let mut next = pos;
+            while next < bytes.len() && bytes[next].is_ascii_whitespace() {
+                next += 1;
+            }
+            if bytes.get(next) != Some(&b'#') {
+                break;
+            }
+            let digits = next + 1;
+            let mut end = digits;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+            let Ok(num) = text[digits..end].parse::<u64>() else {
+                break;
+            };
+            closed.push(num);
+            pos = end;
+            let rest = text[pos..].trim_start();
+            if let Some(rest) = rest.strip_prefix(',') {
+                pos = bytes.len() - rest.len();
+            } else if rest
+                .get(..4)
+                .is_some_and(|w| w.eq_ignore_ascii_case("and "))
+            {
+                pos = bytes.len() - rest.len() + 4;
+            } else {
+                break;
+            }
+        }
     }
+    closed
 }
 
 /// Fast issue number extraction using memchr
@@ -330,6 +514,35 @@ fn extract_issues_fast(text: &str) -> EcoVec<u64> {
     issues
 }
 
+/// GitLab merge-request numbers referenced as `!123`.
+///
+/// The `!` must not follow a word character, so `feat!:` and `wow!1` are
+/// not references.
+fn extract_merge_requests(text: &str) -> EcoVec<u64> {
+    let bytes = text.as_bytes();
+    let mut mrs = EcoVec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = memchr::memchr(b'!', &bytes[pos..]) {
+        let bang = pos + offset;
+        pos = bang + 1;
+        if bang > 0 && (bytes[bang - 1].is_ascii_alphanumeric() || bytes[bang - 1] == b'_') {
+            continue;
+        }
+        let start = pos;
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        if pos > start
+            && let Ok(num) = text[start..pos].parse::<u64>()
+        {
+            mrs.push(num);
+        }
+    }
+
+    mrs
+}
+
 /// Check if a string is a valid footer token
 #[inline]
 fn is_valid_footer_token(token: &str) -> bool {
@@ -339,6 +552,30 @@ fn is_valid_footer_token(token: &str) -> bool {
             .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b' ')
 }
 
+/// Parse the `/scope` in a `type/scope: description` header.
+///
+/// `pos` points at the `/`. The scope must be a single token of
+/// alphanumerics, `-`, `_` or `.` followed by `:` or `!:` (but not `://`);
+/// anything else leaves `pos` untouched and yields no scope.
+#[inline]
+fn parse_slash_scope(summary: &str, pos: &mut usize) -> Option<EcoString> {
+    let bytes = summary.as_bytes();
+    let start = *pos + 1;
+    let mut end = start;
+    while end < bytes.len()
+        && (bytes[end].is_ascii_alphanumeric() || matches!(bytes[end], b'-' | b'_' | b'.'))
+    {
+        end += 1;
+    }
+    let rest = &bytes[end..];
+    let header_end = rest.starts_with(b":") || rest.starts_with(b"!:");
+    if end == start || !header_end || rest.starts_with(b"://") {
+        return None;
+    }
+    *pos = end;
+    Some(summary[start..end].into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,6 +607,66 @@ mod tests {
         assert!(parsed.co_authors.is_empty());
     }
 
+    #[test]
+    fn test_slash_scope() {
+        let opts = ParseOptions {
+            slash_scope: true,
+            ..Default::default()
+        };
+        let parsed = parse_commit_with(&make_commit("feat/api: x", ""), &opts);
+        assert_eq!(parsed.r#type, "feat");
+        assert_eq!(parsed.scope.as_deref(), Some("api"));
+        assert_eq!(parsed.description, "x");
+        assert!(parsed.conventional);
+
+        let parsed = parse_commit_with(&make_commit("fix/core!: drop api", ""), &opts);
+        assert_eq!(parsed.scope.as_deref(), Some("core"));
+        assert!(parsed.breaking);
+
+        // Paren form is unaffected
+        let parsed = parse_commit_with(&make_commit("fix(api): handle null", ""), &opts);
+        assert_eq!(parsed.scope.as_deref(), Some("api"));
+
+        // Disabled by default
+        let parsed = parse_commit_fast(&make_commit("feat/api: x", ""));
+        assert_eq!(parsed.scope, None);
+        assert!(!parsed.conventional);
+    }
+
+    #[test]
+    fn test_slash_scope_ignores_urls() {
+        let opts = ParseOptions {
+            slash_scope: true,
+            ..Default::default()
+        };
+        let parsed = parse_commit_with(&make_commit("see/https://example.com", ""), &opts);
+        assert_eq!(parsed.scope, None);
+        assert!(!parsed.conventional);
+
+        let parsed = parse_commit_with(
+            &make_commit("docs: link https://example.com/a/b: here", ""),
+            &opts,
+        );
+        assert_eq!(parsed.r#type, "docs");
+        assert_eq!(parsed.scope, None);
+        assert_eq!(parsed.description, "link https://example.com/a/b: here");
+    }
+
+    #[test]
+    fn test_unterminated_scope_is_not_conventional() {
+        let parsed = parse_commit_fast(&make_commit("feat(api: thing", ""));
+        assert_eq!(parsed.r#type, "other");
+        assert_eq!(parsed.scope, None);
+        assert_eq!(parsed.description, "feat(api: thing");
+        assert!(!parsed.conventional);
+
+        // A ')' after the colon does not close the scope
+        let parsed = parse_commit_fast(&make_commit("feat(api: fix (x)", ""));
+        assert_eq!(parsed.r#type, "other");
+        assert_eq!(parsed.scope, None);
+        assert_eq!(parsed.description, "feat(api: fix (x)");
+    }
+
     #[test]
     fn test_with_scope() {
         let rc = make_commit("fix(api): handle null", "");
@@ -412,6 +709,47 @@ mod tests {
         assert_eq!(parsed.footers[0].1, "John");
     }
 
+    #[test]
+    fn test_breaking_source() {
+        let parsed = parse_commit_fast(&make_commit("feat!: drop api", ""));
+        assert_eq!(parsed.breaking_source, Some(BreakingSource::Marker));
+
+        let parsed = parse_commit_fast(&make_commit(
+            "feat: drop api",
+            "\nBREAKING CHANGE: the api is gone",
+        ));
+        assert_eq!(parsed.breaking_source, Some(BreakingSource::Footer));
+
+        let parsed = parse_commit_fast(&make_commit(
+            "feat!: drop api",
+            "\nBREAKING CHANGE: the api is gone",
+        ));
+        assert_eq!(parsed.breaking_source, Some(BreakingSource::Marker));
+
+        let parsed = parse_commit_fast(&make_commit("feat: add api", "body text"));
+        assert_eq!(parsed.breaking_source, None);
+    }
+
+    #[test]
+    fn test_footer_like_line_before_body_text() {
+        // A footer-looking line followed by prose is body, not a footer block
+        let rc = make_commit("fix: thing", "Refs: #12\n\nactual body text");
+        let parsed = parse_commit_fast(&rc);
+        assert!(parsed.footers.is_empty());
+        assert_eq!(parsed.body, "Refs: #12\n\nactual body text");
+        assert_eq!(parsed.issues.as_slice(), [12]);
+
+        // Only the trailing run after the final blank line counts as footers
+        let rc = make_commit(
+            "fix: thing",
+            "Refs: #12\n\nactual body text\n\nReviewed-by: Ann",
+        );
+        let parsed = parse_commit_fast(&rc);
+        assert_eq!(parsed.body, "Refs: #12\n\nactual body text");
+        assert_eq!(parsed.footers.len(), 1);
+        assert_eq!(parsed.footers[0].0, "Reviewed-by");
+    }
+
     #[test]
     fn test_breaking_footer() {
         let rc = make_commit("feat: add", "BREAKING CHANGE: breaks stuff");
@@ -428,4 +766,106 @@ mod tests {
         assert_eq!(parsed.co_authors.len(), 1);
         assert_eq!(parsed.co_authors[0], "Jane Doe <jane@example.com>");
     }
+
+    #[test]
+    fn test_co_author_custom_keys() {
+        let rc = make_commit(
+            "feat: add",
+            "Coauthored-by: Jane Doe <jane@example.com>\nCo-Authored-By: Max <max@example.com>",
+        );
+        // Only the standard key by default
+        assert_eq!(
+            parse_commit_fast(&rc).co_authors.as_slice(),
+            ["Max <max@example.com>"]
+        );
+
+        let keys = [
+            EcoString::from("Co-authored-by"),
+            EcoString::from("coauthored-by"),
+        ];
+        let opts = ParseOptions {
+            co_author_keys: &keys,
+            ..Default::default()
+        };
+        let parsed = parse_commit_with(&rc, &opts);
+        assert_eq!(
+            parsed.co_authors.as_slice(),
+            ["Jane Doe <jane@example.com>", "Max <max@example.com>"]
+        );
+    }
+
+    #[test]
+    fn test_reverted_commit() {
+        let rc = make_commit(
+            "revert: feat: add thing",
+            "This reverts commit 0123456789abcdef0123456789abcdef01234567.",
+        );
+        let parsed = parse_commit_fast(&rc);
+        assert_eq!(
+            parsed.reverts.as_deref(),
+            Some("0123456789abcdef0123456789abcdef01234567")
+        );
+
+        let merge = make_commit(
+            "Revert \"Merge branch 'x'\"",
+            "This reverts commit abcdef1, reversing\nchanges made to 1234567.",
+        );
+        assert_eq!(
+            parse_commit_fast(&merge).reverts.as_deref(),
+            Some("abcdef1")
+        );
+
+        let plain = make_commit("fix: thing", "This reverts commit soon.");
+        assert_eq!(parse_commit_fast(&plain).reverts, None);
+    }
+
+    #[test]
+    fn test_closed_issues() {
+        let rc = make_commit(
+            "fix: crash on #7 and resolve #5",
+            "Mentions #9 in passing.\n\nCLOSES #1, #2 and #3\nFixes: #4",
+        );
+        let parsed = parse_commit_fast(&rc);
+        assert_eq!(parsed.closed_issues.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(parsed.issues.as_slice(), &[1, 2, 3, 4, 5, 7, 9]);
+
+        let header = make_commit("fix: #12 overflow", "prefixed #3 isn't a keyword");
+        assert!(parse_commit_fast(&header).closed_issues.is_empty());
+    }
+
+    #[test]
+    fn test_merge_requests() {
+        let rc = make_commit(
+            "feat!: support tokens (#12, !34)",
+            "Follow-up to !7, wow!8 isn't one.\n\nRefs: #12, !34",
+        );
+        let opts = ParseOptions {
+            merge_requests: true,
+            ..Default::default()
+        };
+        let parsed = parse_commit_with(&rc, &opts);
+        assert_eq!(parsed.issues.as_slice(), &[12]);
+        assert_eq!(parsed.merge_requests.as_slice(), &[7, 34]);
+        assert!(parsed.breaking);
+
+        // Outside GitLab `!34` is plain text
+        assert!(parse_commit_fast(&rc).merge_requests.is_empty());
+    }
+
+    #[test]
+    fn test_squash_pr_number() {
+        let rc = make_commit("feat: add feature (#42)", "");
+        assert_eq!(parse_commit_fast(&rc).pr, Some(42));
+
+        let rc = make_commit("fix: see #42 for details", "");
+        assert_eq!(parse_commit_fast(&rc).pr, None);
+    }
+
+    #[test]
+    fn test_conventional_header_detection() {
+        assert!(parse_commit_fast(&make_commit("feat(core)!: add thing", "")).conventional);
+        assert!(!parse_commit_fast(&make_commit("Update readme", "")).conventional);
+        assert!(!parse_commit_fast(&make_commit("fix(core) no colon", "")).conventional);
+        assert!(!parse_commit_fast(&make_commit("fix:", "")).conventional);
+    }
 }
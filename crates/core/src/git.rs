@@ -1,3 +1,6 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
 use ecow::{EcoString, EcoVec};
 use gix::Repository;
 use gix::date::parse::TimeBuf;
@@ -6,7 +9,7 @@ use gix::date::parse::TimeBuf;
 ///
 /// Contains essential commit metadata needed for changelog generation.
 /// This is a lightweight representation optimized for performance.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct RawCommit {
     pub id: EcoString,
     pub short_id: EcoString,
@@ -46,8 +49,118 @@ pub fn detect_repo(path: &std::path::Path) -> anyhow::Result<Repository> {
 /// * `Ok(None)` - No semantic version tags found
 /// * `Err` - Repository access error
 pub fn last_tag(repo: &Repository) -> anyhow::Result<Option<EcoString>> {
+    last_tag_with(repo, parse_v_tag)
+}
+
+/// Like [`last_tag`], reading versions out of tag names with `parse_version`.
+pub fn last_tag_with(
+    repo: &Repository,
+    parse_version: impl Fn(&str) -> Option<semver::Version>,
+) -> anyhow::Result<Option<EcoString>> {
+    let mut latest: Option<SemverTag> = None;
+    for tag in semver_tags(repo, parse_version)? {
+        let newer = latest.as_ref().is_none_or(|lt| {
+            tag.time > lt.time || (tag.time == lt.time && tag.version > lt.version)
+        });
+        if newer {
+            latest = Some(tag);
+        }
+    }
+    Ok(latest.map(|t| t.name))
+}
+
+/// Find the highest semantic version tag whose commit is reachable from `head`.
+///
+/// Unlike [`last_tag`], tags on branches that were never merged into `head`
+/// are ignored, and ordering is by version rather than commit time.
+///
+/// # Arguments
+/// * `repo` - Git repository to search
+/// * `head` - Revision the tags must be ancestors of (e.g. `HEAD`)
+///
+/// # Returns
+/// * `Ok(Some(tag_name))` - Highest reachable semantic version tag
+/// * `Ok(None)` - No reachable semantic version tags found
+/// * `Err` - Repository access error
+pub fn max_reachable_semver_tag(
+    repo: &Repository,
+    head: &str,
+) -> anyhow::Result<Option<EcoString>> {
+    max_reachable_semver_tag_with(repo, head, parse_v_tag)
+}
+
+/// Like [`max_reachable_semver_tag`], reading versions out of tag names with `parse_version`.
+pub fn max_reachable_semver_tag_with(
+    repo: &Repository,
+    head: &str,
+    parse_version: impl Fn(&str) -> Option<semver::Version>,
+) -> anyhow::Result<Option<EcoString>> {
+    let head_id = repo
+        .rev_parse_single(head)?
+        .object()?
+        .peel_to_kind(gix::object::Kind::Commit)?
+        .id;
+    let mut best: Option<SemverTag> = None;
+    for tag in semver_tags(repo, parse_version)? {
+        if best.as_ref().is_some_and(|b| tag.version <= b.version) {
+            continue;
+        }
+        // The tag is an ancestor of head when it is their merge base
+        let reachable = tag.commit == head_id
+            || repo
+                .merge_base(tag.commit, head_id)
+                .is_ok_and(|base| base.detach() == tag.commit);
+        if reachable {
+            best = Some(tag);
+        }
+    }
+    Ok(best.map(|t| t.name))
+}
+
+/// Resolve the best common ancestor of two revisions.
+///
+/// # Arguments
+/// * `repo` - Git repository
+/// * `one` - First revision (e.g. a base branch)
+/// * `two` - Second revision (e.g. `HEAD`)
+///
+/// # Returns
+/// * `Ok(id)` - Merge-base commit id
+/// * `Err` - A revision could not be resolved or the histories are unrelated
+pub fn merge_base(repo: &Repository, one: &str, two: &str) -> anyhow::Result<gix::ObjectId> {
+    let resolve = |rev: &str| -> anyhow::Result<gix::ObjectId> {
+        Ok(repo
+            .rev_parse_single(rev)?
+            .object()?
+            .peel_to_kind(gix::object::Kind::Commit)?
+            .id)
+    };
+    let base = repo
+        .merge_base(resolve(one)?, resolve(two)?)
+        .map_err(|e| anyhow::anyhow!("no merge base between {one} and {two}: {e}"))?;
+    Ok(base.detach())
+}
+
+/// A tag whose name parses as a semantic version (with optional 'v' prefix).
+struct SemverTag {
+    name: EcoString,
+    commit: gix::ObjectId,
+    time: i64,
+    version: semver::Version,
+}
+
+/// Default tag parser: a semantic version with an optional 'v' prefix.
+fn parse_v_tag(tag: &str) -> Option<semver::Version> {
+    semver::Version::parse(tag.trim_start_matches('v')).ok()
+}
+
+/// Collect all semantic version tags, peeled to the commits they point at.
+fn semver_tags(
+    repo: &Repository,
+    parse_version: impl Fn(&str) -> Option<semver::Version>,
+) -> anyhow::Result<Vec<SemverTag>> {
     use gix::object::Kind;
-    let mut latest: Option<(EcoString, i64, semver::Version)> = None;
+    let mut tags = Vec::new();
     let refs = repo.references().map_err(anyhow::Error::from)?;
     for result in refs.all()? {
         let mut tag_ref = match result {
@@ -60,10 +173,8 @@ pub fn last_tag(repo: &Repository) -> anyhow::Result<Option<EcoString>> {
         }
         let tag_name_bstr = &name_bstr[b"refs/tags/".len()..];
         let tag_name = String::from_utf8_lossy(tag_name_bstr).to_string();
-        let ver_str = tag_name.trim_start_matches('v');
-        let parsed = match semver::Version::parse(ver_str) {
-            Ok(v) => v,
-            Err(_) => continue,
+        let Some(parsed) = parse_version(&tag_name) else {
+            continue;
         };
         // Peel to commit for annotated tags, or use target for lightweight
         let target_commit_oid = match tag_ref.peel_to_kind(Kind::Commit) {
@@ -74,17 +185,14 @@ pub fn last_tag(repo: &Repository) -> anyhow::Result<Option<EcoString>> {
             Ok(c) => c,
             Err(_) => continue,
         };
-        let time = commit.time().map(|t| t.seconds).unwrap_or(0);
-        match &latest {
-            None => latest = Some((tag_name.clone().into(), time, parsed)),
-            Some((_, lt_time, lt_ver)) => {
-                if time > *lt_time || (time == *lt_time && &parsed > lt_ver) {
-                    latest = Some((tag_name.clone().into(), time, parsed));
-                }
-            }
-        }
+        tags.push(SemverTag {
+            name: tag_name.into(),
+            commit: target_commit_oid,
+            time: commit.time().map(|t| t.seconds).unwrap_or(0),
+            version: parsed,
+        });
     }
-    Ok(latest.map(|(n, _, _)| n))
+    Ok(tags)
 }
 
 /// Get the current HEAD reference name.
@@ -107,15 +215,19 @@ pub fn current_ref(repo: &Repository) -> anyhow::Result<Option<EcoString>> {
         if let Some(target_id) = head.id() {
             let refs = repo.references().map_err(anyhow::Error::from)?;
             for result in refs.all()? {
-                let tag_ref = match result {
+                let mut tag_ref = match result {
                     Ok(r) => r,
                     Err(_) => continue,
                 };
-                let name_bstr = tag_ref.name().as_bstr();
-                if name_bstr.starts_with(b"refs/tags/")
-                    && let Some(tag_oid) = tag_ref.target().try_id()
-                    && *tag_oid == *target_id
-                {
+                if !tag_ref.name().as_bstr().starts_with(b"refs/tags/") {
+                    continue;
+                }
+                // Annotated tags point at a tag object; peel to the tagged commit
+                let Ok(tagged) = tag_ref.peel_to_kind(gix::object::Kind::Commit) else {
+                    continue;
+                };
+                if *tagged.id == *target_id {
+                    let name_bstr = tag_ref.name().as_bstr();
                     let tag_name_bstr = &name_bstr[b"refs/tags/".len()..];
                     let tag_name = String::from_utf8_lossy(tag_name_bstr).to_string();
                     return Ok(Some(tag_name.into()));
@@ -143,6 +255,7 @@ pub fn current_ref(repo: &Repository) -> anyhow::Result<Option<EcoString>> {
 /// * `repo` - Git repository
 /// * `from` - Optional starting reference (exclusive)
 /// * `to` - Ending reference (inclusive)
+/// * `limit` - Keep only the most recent N commits of the range (warns when it cuts history)
 ///
 /// # Returns
 /// * `Ok(commits)` - Vector of raw commits in chronological order (oldest first)
@@ -151,6 +264,73 @@ pub fn commits_between(
     repo: &Repository,
     from: Option<&str>,
     to: &str,
+    limit: Option<usize>,
+) -> anyhow::Result<EcoVec<RawCommit>> {
+    commits_between_filtered(repo, from, to, &PathFilter::default(), limit)
+}
+
+/// Path-based filter applied to commits while collecting a range.
+#[derive(Debug, Clone, Default)]
+pub struct PathFilter {
+    /// When non-empty, only commits touching these paths are kept
+    pub include: EcoVec<PathBuf>,
+    /// Commits whose changes all fall under these paths are dropped
+    pub exclude: EcoVec<PathBuf>,
+}
+
+impl PathFilter {
+    /// Whether the filter has no effect.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether a commit changing `paths` passes the filter.
+    ///
+    /// A commit is kept when at least one changed path is included (or no
+    /// include set is given) and not excluded. Without an include set, a
+    /// commit without any changed paths is kept too.
+    pub fn keeps(&self, paths: &[PathBuf]) -> bool {
+        if paths.is_empty() {
+            return self.include.is_empty();
+        }
+        paths.iter().any(|p| {
+            (self.include.is_empty() || self.include.iter().any(|inc| p.starts_with(inc)))
+                && !self.exclude.iter().any(|ex| p.starts_with(ex))
+        })
+    }
+}
+
+/// List the files changed by a commit relative to its first parent.
+///
+/// Root commits are compared against the empty tree. Paths are relative
+/// to the repository root.
+pub fn changed_paths(repo: &Repository, id: gix::ObjectId) -> anyhow::Result<Vec<PathBuf>> {
+    use gix::bstr::ByteSlice;
+    let commit = repo.find_commit(id)?;
+    let tree = commit.tree()?;
+    let parent_tree = match commit.parent_ids().next() {
+        Some(parent) => repo.find_commit(parent)?.tree()?,
+        None => repo.empty_tree(),
+    };
+    let changes = repo.diff_tree_to_tree(&parent_tree, &tree, gix::diff::Options::default())?;
+    Ok(changes
+        .iter()
+        .filter(|change| !change.entry_mode().is_tree())
+        .map(|change| Path::new(&*change.location().to_str_lossy()).to_path_buf())
+        .collect())
+}
+
+/// Like [`commits_between`], but drops commits rejected by `filter`.
+///
+/// Changed paths are only computed when the filter is non-empty; a root
+/// commit's paths are the files it adds. `limit` counts the commits the
+/// filter keeps.
+pub fn commits_between_filtered(
+    repo: &Repository,
+    from: Option<&str>,
+    to: &str,
+    filter: &PathFilter,
+    limit: Option<usize>,
 ) -> anyhow::Result<EcoVec<RawCommit>> {
     // Use environment variable to control parallelism threshold
     let threshold = std::env::var("NOVALYN_GIT_PARALLEL_THRESHOLD")
@@ -158,7 +338,52 @@ pub fn commits_between(
         .and_then(|s| s.parse().ok())
         .unwrap_or(100);
 
-    // First, collect all commit IDs
+    // First, collect the commit IDs, newest first, stopping at the limit
+    let mut commit_ids = Vec::new();
+    for id in range_walk(repo, from, to)? {
+        if !filter.is_empty() && !filter.keeps(&changed_paths(repo, id)?) {
+            tracing::debug!(%id, "commit dropped by path filter");
+            continue;
+        }
+        if let Some(limit) = limit
+            && commit_ids.len() >= limit
+        {
+            tracing::warn!(
+                limit,
+                "commit limit reached; older commits are left out and the changelog is partial"
+            );
+            break;
+        }
+        commit_ids.push(id);
+    }
+
+    let count = commit_ids.len();
+
+    // Choose strategy based on count
+    if count >= threshold {
+        tracing::debug!(count, threshold, "using parallel git commit processing");
+        commits_between_parallel(repo, commit_ids)
+    } else {
+        tracing::debug!(count, threshold, "using sequential git commit processing");
+        commits_between_sequential(repo, commit_ids)
+    }
+}
+
+/// Commit ids reachable from `to` but not from `from`, newest first.
+fn range_ids(
+    repo: &Repository,
+    from: Option<&str>,
+    to: &str,
+) -> anyhow::Result<Vec<gix::ObjectId>> {
+    Ok(range_walk(repo, from, to)?.collect())
+}
+
+/// Lazy walk over the ids of [`range_ids`].
+fn range_walk<'repo>(
+    repo: &'repo Repository,
+    from: Option<&str>,
+    to: &str,
+) -> anyhow::Result<impl Iterator<Item = gix::ObjectId> + 'repo> {
     let to_obj = repo.rev_parse_single(to).map_err(anyhow::Error::from)?;
     let to_id = to_obj.object()?.peel_to_kind(gix::object::Kind::Commit)?.id;
     let mut walk = repo.rev_walk([to_id]);
@@ -173,20 +398,68 @@ pub fn commits_between(
         walk = walk.with_hidden([from_id]);
     }
 
-    let commit_ids: Vec<_> = walk
-        .all()?
-        .filter_map(|info| info.ok().map(|i| i.id))
-        .collect();
+    Ok(walk.all()?.filter_map(|info| info.ok().map(|i| i.id)))
+}
 
-    let count = commit_ids.len();
+/// Lazily yield the commits between two revisions, oldest first.
+///
+/// Only the commit ids of the range are resolved up front; each commit's
+/// message and author are read when the iterator reaches it, so callers can
+/// parse commits as they arrive instead of holding the whole range in memory.
+/// Yields the same commits in the same order as [`commits_between`].
+///
+/// # Arguments
+/// * `repo` - Git repository
+/// * `from` - Optional starting revision (exclusive)
+/// * `to` - Ending revision (inclusive)
+///
+/// # Returns
+/// * `Ok(iter)` - Iterator over the commits of the range
+/// * `Err` - A revision could not be resolved
+pub fn commits_iter<'repo>(
+    repo: &'repo Repository,
+    from: Option<&str>,
+    to: &str,
+) -> anyhow::Result<CommitIter<'repo>> {
+    let mut ids = range_ids(repo, from, to)?;
+    ids.reverse();
+    Ok(CommitIter {
+        repo,
+        ids: ids.into_iter(),
+    })
+}
 
-    // Choose strategy based on count
-    if count >= threshold {
-        tracing::debug!(count, threshold, "using parallel git commit processing");
-        commits_between_parallel(repo, commit_ids)
-    } else {
-        tracing::debug!(count, threshold, "using sequential git commit processing");
-        commits_between_sequential(repo, commit_ids)
+/// Iterator returned by [`commits_iter`].
+///
+/// Commits that cannot be read are skipped with a warning, matching
+/// [`commits_between`].
+pub struct CommitIter<'repo> {
+    repo: &'repo Repository,
+    ids: std::vec::IntoIter<gix::ObjectId>,
+}
+
+impl Iterator for CommitIter<'_> {
+    type Item = RawCommit;
+
+    fn next(&mut self) -> Option<RawCommit> {
+        for commit_id in self.ids.by_ref() {
+            let commit = match self.repo.find_commit(commit_id) {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::warn!("Skipping commit {}: {}", commit_id, e);
+                    continue;
+                }
+            };
+            match to_raw_commit(&commit) {
+                Ok(raw) => return Some(raw),
+                Err(e) => tracing::warn!("Skipping commit {}: {}", commit_id, e),
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.ids.len()))
     }
 }
 
@@ -330,8 +603,10 @@ pub fn add_and_commit(repo: &mut Repository, message: &str) -> anyhow::Result<gi
     // Create tree editor
     let mut tree_editor = repo.edit_tree(base_tree_id)?;
 
-    // Get the status to find files to add
-    let status_platform = repo.status(gix::progress::Discard)?;
+    // Get the status to find files to add (list files inside untracked directories individually)
+    let status_platform = repo
+        .status(gix::progress::Discard)?
+        .untracked_files(gix::status::UntrackedFiles::Files);
     let status_iter = status_platform.into_iter(None)?;
 
     // Process status items to find files to add
@@ -434,6 +709,90 @@ pub fn create_tag(
     }
 }
 
+/// Create a GPG-signed annotated tag pointing at HEAD.
+///
+/// Signs like `git tag -s`: the key comes from `user.signingkey` (falling back
+/// to the committer email) and the program from `gpg.program` (default `gpg`).
+///
+/// # Errors
+/// Returns error if the signing program can't be run or fails; no tag is
+/// created in that case.
+pub fn create_signed_tag(
+    repo: &mut Repository,
+    name: &str,
+    message: &str,
+) -> anyhow::Result<gix::ObjectId> {
+    use gix::objs::WriteTo;
+    let head_commit_id = repo.head_id().map_err(anyhow::Error::from)?.detach();
+    let tagger = repo
+        .committer_or_set_generic_fallback()
+        .map_err(anyhow::Error::from)?
+        .to_owned()
+        .map_err(anyhow::Error::from)?;
+    let config = repo.config_snapshot();
+    let key = match config.string("user.signingkey") {
+        Some(key) => key.to_string(),
+        None => tagger.email.to_string(),
+    };
+    let program = config
+        .trusted_program("gpg.program")
+        .map_or_else(|| "gpg".into(), |p| p.into_owned());
+
+    let mut tag = gix::objs::Tag {
+        target: head_commit_id,
+        target_kind: gix::object::Kind::Commit,
+        name: name.into(),
+        tagger: Some(tagger),
+        message: message.into(),
+        pgp_signature: None,
+    };
+    // The signed payload ends with the newline that separates message and signature
+    let mut payload = Vec::new();
+    tag.write_to(&mut payload)?;
+    payload.push(b'\n');
+    tag.pgp_signature = Some(gpg_sign(&program, &key, &payload)?.into());
+
+    let tag_id = repo.write_object(&tag)?.detach();
+    repo.tag_reference(
+        name,
+        tag_id,
+        gix::refs::transaction::PreviousValue::MustNotExist,
+    )?;
+    Ok(tag_id)
+}
+
+/// Produce a detached ASCII-armored signature of `payload` with `program`.
+fn gpg_sign(program: &std::ffi::OsStr, key: &str, payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+    let mut child = Command::new(program)
+        .args(["--status-fd=2", "-bsau", key])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("running signing program {}", program.to_string_lossy()))?;
+    // A signer that exits early closes the pipe; its status explains why
+    let written = child
+        .stdin
+        .take()
+        .context("signing program stdin unavailable")?
+        .write_all(payload);
+    let output = child.wait_with_output()?;
+    if output.status.success() {
+        written?;
+    }
+    let signature = output.stdout;
+    if !output.status.success() || !signature.starts_with(b"-----BEGIN ") {
+        anyhow::bail!(
+            "failed to sign tag with key '{key}' ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(signature)
+}
+
 pub fn init_repo(path: &std::path::Path) -> anyhow::Result<Repository> {
     // Initialize repository at path
     let mut repo = gix::init(path)?;
@@ -1,9 +1,30 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use ecow::{EcoString, EcoVec};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, instrument, warn};
 
 use crate::repository::Repository;
 
+/// Public GitHub REST API endpoint.
+pub const DEFAULT_API_BASE: &str = "https://api.github.com";
+
+/// Maximum number of GitHub API requests in flight at once, across all callers.
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+static REQUEST_PERMITS: tokio::sync::Semaphore =
+    tokio::sync::Semaphore::const_new(MAX_CONCURRENT_REQUESTS);
+
+/// Retries of a rate-limited request (override with `NOVALYN_GITHUB_MAX_RETRIES`).
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// First backoff delay when the response doesn't say how long to wait.
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Rate limits resetting later than this are not waited out.
+const MAX_RETRY_WAIT: Duration = Duration::from_secs(60);
+
 /// Information about a GitHub release operation.
 ///
 /// Contains details about whether a release was created, updated, or skipped.
@@ -56,8 +77,8 @@ pub async fn get_username_from_email(
         return Ok(None); // No token, can't query API
     };
 
-    let api_base = api_base.unwrap_or("https://api.github.com");
-    let client = reqwest::Client::new();
+    let api_base = api_base.unwrap_or(DEFAULT_API_BASE);
+    let client = client();
     let search_url = format!(
         "{}/search/users?q={}+in:email",
         api_base,
@@ -66,13 +87,13 @@ pub async fn get_username_from_email(
 
     debug!("searching GitHub for email" = %email);
 
-    let resp = client
-        .get(&search_url)
-        .header("User-Agent", "novalyn")
-        .bearer_auth(token)
-        .send()
-        .await
-        .map_err(|e| GithubError::Network(e.to_string()))?;
+    let resp = send(
+        client
+            .get(&search_url)
+            .header("User-Agent", "novalyn")
+            .bearer_auth(token),
+    )
+    .await?;
 
     if !resp.status().is_success() {
         warn!(status = %resp.status(), "github user search failed");
@@ -96,23 +117,275 @@ pub async fn get_username_from_email(
     }
 }
 
+/// HTTP client for GitHub API calls.
+///
+/// Installs the rustls crypto provider first, so embedders that never called
+/// [`crate::init_crypto_provider`] don't hit a panic inside reqwest.
+fn client() -> reqwest::Client {
+    crate::init_crypto_provider();
+    reqwest::Client::new()
+}
+
+/// Send a GitHub API request, retrying when rate limited.
+///
+/// At most [`MAX_CONCURRENT_REQUESTS`] requests are in flight. A 429, or a 403
+/// with `Retry-After` or an exhausted `X-RateLimit-Remaining`, is retried after
+/// the time the headers ask for, or after an exponential backoff with jitter.
+/// The last response is returned once retries run out.
+async fn send(request: reqwest::RequestBuilder) -> Result<reqwest::Response, GithubError> {
+    let max_retries = std::env::var("NOVALYN_GITHUB_MAX_RETRIES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_MAX_RETRIES);
+    let mut attempt = 0;
+    loop {
+        let req = request
+            .try_clone()
+            .ok_or_else(|| GithubError::Network("request body cannot be retried".into()))?;
+        let resp = {
+            let _permit = REQUEST_PERMITS
+                .acquire()
+                .await
+                .map_err(|e| GithubError::Network(e.to_string()))?;
+            req.send()
+                .await
+                .map_err(|e| GithubError::Network(e.to_string()))?
+        };
+        let Some(wait) = rate_limit_wait(&resp, attempt) else {
+            return Ok(resp);
+        };
+        if attempt >= max_retries || wait > MAX_RETRY_WAIT {
+            return Ok(resp);
+        }
+        attempt += 1;
+        debug!(
+            status = resp.status().as_u16(),
+            attempt,
+            wait_ms = wait.as_millis() as u64,
+            "rate limited by GitHub; retrying"
+        );
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// How long to wait before retrying `resp`, or `None` if it wasn't rate limited.
+fn rate_limit_wait(resp: &reqwest::Response, attempt: u32) -> Option<Duration> {
+    let header = |name: &str| resp.headers().get(name).and_then(|v| v.to_str().ok());
+    let retry_after = header("retry-after")
+        .and_then(|v| v.trim().parse().ok())
+        .map(Duration::from_secs);
+    let exhausted = header("x-ratelimit-remaining") == Some("0");
+    let limited = match resp.status().as_u16() {
+        429 => true,
+        403 => retry_after.is_some() || exhausted,
+        _ => false,
+    };
+    if !limited {
+        return None;
+    }
+    if retry_after.is_some() {
+        return retry_after;
+    }
+    if exhausted && let Some(reset) = header("x-ratelimit-reset").and_then(|v| v.parse().ok()) {
+        return Some(Duration::from_secs(u64::saturating_sub(
+            reset,
+            unix_now().as_secs(),
+        )));
+    }
+    Some(backoff(attempt))
+}
+
+/// Exponential backoff for `attempt` with up to 50% jitter.
+fn backoff(attempt: u32) -> Duration {
+    let delay = BASE_BACKOFF * 2u32.pow(attempt.min(6));
+    let jitter = f64::from(unix_now().subsec_nanos() % 1000) / 2000.0;
+    delay + delay.mul_f64(jitter)
+}
+
+fn unix_now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Maximum number of concurrent label requests issued by [`fetch_pr_labels`].
+const LABEL_CONCURRENCY: usize = 8;
+
+/// Fetch the label names of a single pull request.
+/// `api_base` parameter allows testing with mock servers (defaults to "https://api.github.com")
+#[instrument(skip(repo, token, api_base))]
+pub async fn get_pr_labels(
+    repo: &Repository,
+    pr: u64,
+    token: &str,
+    api_base: Option<&str>,
+) -> Result<EcoVec<EcoString>, GithubError> {
+    if repo.provider != crate::repository::Provider::GitHub {
+        return Err(GithubError::NotGithub);
+    }
+    let api_base = api_base.unwrap_or(DEFAULT_API_BASE);
+    let url = format!(
+        "{}/repos/{}/{}/pulls/{}",
+        api_base, repo.owner, repo.name, pr
+    );
+    let resp = send(
+        client()
+            .get(&url)
+            .header("User-Agent", "novalyn")
+            .bearer_auth(token),
+    )
+    .await?;
+    if !resp.status().is_success() {
+        return Err(GithubError::Status(resp.status().as_u16()));
+    }
+
+    #[derive(Clone, Deserialize)]
+    struct Label {
+        name: EcoString,
+    }
+    #[derive(Deserialize)]
+    struct PullResp {
+        #[serde(default)]
+        labels: EcoVec<Label>,
+    }
+
+    let data: PullResp = resp
+        .json()
+        .await
+        .map_err(|e| GithubError::Network(e.to_string()))?;
+    Ok(data.labels.iter().map(|l| l.name.clone()).collect())
+}
+
+/// Fetch labels for many pull requests with bounded concurrency.
+///
+/// Each PR number is requested at most once; failed lookups are logged and
+/// yield no entry in the returned map.
+pub async fn fetch_pr_labels(
+    repo: &Repository,
+    prs: impl IntoIterator<Item = u64>,
+    token: &str,
+    api_base: Option<&str>,
+) -> BTreeMap<u64, EcoVec<EcoString>> {
+    use futures::stream::{self, StreamExt};
+
+    let unique: BTreeSet<u64> = prs.into_iter().collect();
+    stream::iter(unique)
+        .map(|pr| async move { (pr, get_pr_labels(repo, pr, token, api_base).await) })
+        .buffer_unordered(LABEL_CONCURRENCY)
+        .filter_map(|(pr, res)| async move {
+            match res {
+                Ok(labels) => Some((pr, labels)),
+                Err(e) => {
+                    warn!(pr, error = %e, "failed to fetch pull request labels");
+                    None
+                }
+            }
+        })
+        .collect()
+        .await
+}
+
 /// Sync release with GitHub: get existing by tag, create or update.
 /// Returns ReleaseInfo even on fallback path (manual URL) with skipped=true.
+/// `prerelease: None` marks the release as a prerelease when the tag's version
+/// has a prerelease component.
 /// `api_base` parameter allows testing with mock servers (defaults to "https://api.github.com")
-#[instrument(skip(token, body, api_base), fields(tag = %tag))]
 pub async fn sync_release(
     repo: &Repository,
     token: Option<&str>,
     tag: &str,
     body: &str,
+    draft: bool,
+    prerelease: Option<bool>,
     api_base: Option<&str>,
 ) -> Result<ReleaseInfo, GithubError> {
+    let payload = ReleasePayload {
+        tag,
+        name: tag,
+        body,
+        draft,
+        prerelease,
+    };
+    sync_release_with(repo, token, &payload, api_base).await
+}
+
+/// Fields sent to GitHub when creating or updating a release.
+#[derive(Debug, Clone, Copy)]
+pub struct ReleasePayload<'a> {
+    /// Tag the release is attached to
+    pub tag: &'a str,
+    /// Release title shown on GitHub
+    pub name: &'a str,
+    /// Release notes
+    pub body: &'a str,
+    /// Save the release as an unpublished draft
+    pub draft: bool,
+    /// Mark the release as a prerelease (`None` detects it from the tag)
+    pub prerelease: Option<bool>,
+}
+
+/// Whether the version in `tag` (e.g. `v1.0.0-rc.1`) has a prerelease component.
+pub fn tag_is_prerelease(tag: &str) -> bool {
+    tag.char_indices()
+        .find_map(|(i, _)| semver::Version::parse(&tag[i..]).ok())
+        .is_some_and(|v| !v.pre.is_empty())
+}
+
+/// JSON body posted to create a release.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRelease<'a> {
+    pub tag_name: &'a str,
+    pub name: &'a str,
+    pub body: &'a str,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+impl<'a> ReleasePayload<'a> {
+    /// Request body sent to create this release.
+    pub fn create_request(&self) -> CreateRelease<'a> {
+        CreateRelease {
+            tag_name: self.tag,
+            name: self.name,
+            body: self.body,
+            draft: self.draft,
+            prerelease: self.is_prerelease(),
+        }
+    }
+
+    /// The prerelease flag sent to GitHub.
+    pub fn is_prerelease(&self) -> bool {
+        self.prerelease
+            .unwrap_or_else(|| tag_is_prerelease(self.tag))
+    }
+
+    /// The create request as pretty-printed JSON, e.g. for dry runs.
+    pub fn request_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.create_request())
+    }
+}
+
+/// Like [`sync_release`], with an explicit release title.
+#[instrument(skip(token, payload, api_base), fields(tag = %payload.tag))]
+pub async fn sync_release_with(
+    repo: &Repository,
+    token: Option<&str>,
+    payload: &ReleasePayload<'_>,
+    api_base: Option<&str>,
+) -> Result<ReleaseInfo, GithubError> {
+    let ReleasePayload {
+        tag,
+        name,
+        body,
+        draft,
+        ..
+    } = *payload;
     if repo.provider != crate::repository::Provider::GitHub {
         return Err(GithubError::NotGithub);
     }
     let manual_url = format!(
-        "https://github.com/{}/{}/releases/tag/{}",
-        repo.owner, repo.name, tag
+        "https://{}/{}/{}/releases/tag/{}",
+        repo.host, repo.owner, repo.name, tag
     );
     let Some(token) = token else {
         // No token -> fallback manual URL, mark skipped
@@ -124,44 +397,29 @@ pub async fn sync_release(
             skipped: true,
         });
     };
-    let client = reqwest::Client::new();
-    let api_base = api_base.unwrap_or("https://api.github.com");
+    let client = client();
+    let api_base = api_base.unwrap_or(DEFAULT_API_BASE);
     let releases_base = format!("{}/repos/{}/{}/releases", api_base, repo.owner, repo.name);
     // 1. Try get by tag
     let get_url = format!("{}/tags/{}", releases_base, tag);
     debug!("github_get_tag" = %get_url, "attempting fetch existing release");
-    let existing = client
-        .get(&get_url)
-        .header("User-Agent", "novalyn")
-        .bearer_auth(token)
-        .send()
-        .await
-        .map_err(|e| GithubError::Network(e.to_string()))?;
+    let existing = send(
+        client
+            .get(&get_url)
+            .header("User-Agent", "novalyn")
+            .bearer_auth(token),
+    )
+    .await?;
     if existing.status().as_u16() == 404 {
         // create new
-        #[derive(Serialize)]
-        struct CreateRelease<'a> {
-            tag_name: &'a str,
-            name: &'a str,
-            body: &'a str,
-            draft: bool,
-            prerelease: bool,
-        }
-        let payload = CreateRelease {
-            tag_name: tag,
-            name: tag,
-            body,
-            draft: false,
-            prerelease: false,
-        };
-        let resp = client
-            .post(&releases_base)
-            .header("User-Agent", "novalyn")
-            .bearer_auth(token)
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|e| GithubError::Network(e.to_string()))?;
+        let resp = send(
+            client
+                .post(&releases_base)
+                .header("User-Agent", "novalyn")
+                .bearer_auth(token)
+                .json(&payload.create_request()),
+        )
+        .await?;
         if !resp.status().is_success() {
             return Err(GithubError::Status(resp.status().as_u16()));
         }
@@ -193,17 +451,25 @@ pub async fn sync_release(
         // update body if differs (simple unconditional patch)
         #[derive(Serialize)]
         struct UpdateRelease<'a> {
+            name: &'a str,
             body: &'a str,
+            draft: bool,
+            prerelease: bool,
         }
         let patch_url = format!("{}/{}", releases_base, data.id);
-        let resp = client
-            .patch(&patch_url)
-            .header("User-Agent", "novalyn")
-            .bearer_auth(token)
-            .json(&UpdateRelease { body })
-            .send()
-            .await
-            .map_err(|e| GithubError::Network(e.to_string()))?;
+        let resp = send(
+            client
+                .patch(&patch_url)
+                .header("User-Agent", "novalyn")
+                .bearer_auth(token)
+                .json(&UpdateRelease {
+                    name,
+                    body,
+                    draft,
+                    prerelease: payload.is_prerelease(),
+                }),
+        )
+        .await?;
         if !resp.status().is_success() {
             warn!(status = %resp.status(), "github update release failed");
         }
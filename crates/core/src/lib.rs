@@ -10,15 +10,18 @@ pub mod github;
 pub mod parse;
 pub mod pipeline;
 pub mod render;
+pub mod report;
 pub mod repository;
 pub mod utils;
+pub mod workspace;
 
 pub use ecow;
 pub use semver;
 pub use tokio;
 
 /// Initialize the rustls cryptographic provider.
-/// This must be called before using any TLS functionality (e.g., reqwest with wiremock).
+/// This must be called before using any TLS functionality (e.g., reqwest with wiremock);
+/// the [`github`] functions call it themselves.
 /// It's safe to call multiple times - subsequent calls are no-ops.
 pub fn init_crypto_provider() {
     let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
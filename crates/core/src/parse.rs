@@ -1,6 +1,9 @@
-use crate::config::{ResolvedConfig, SemverImpact, TypeConfigResolved};
-use crate::conventional::parse_commit_fast;
+use crate::config::{
+    OnEmptyDescription, ResolvedConfig, SemverImpact, TypeConfigResolved, ZeroMajorFeat,
+};
+use crate::conventional::{BreakingSource, ParseOptions, parse_commit_with};
 use crate::git::RawCommit;
+use crate::repository::Provider;
 use ecow::{EcoString, EcoVec};
 use rayon::prelude::*;
 
@@ -8,7 +11,7 @@ use rayon::prelude::*;
 ///
 /// Contains both the original raw commit data and extracted conventional
 /// commit fields like type, scope, breaking changes, and issue references.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ParsedCommit {
     pub raw: RawCommit,
     pub r#type: EcoString,
@@ -17,8 +20,24 @@ pub struct ParsedCommit {
     pub body: EcoString,
     pub footers: EcoVec<(EcoString, EcoString)>,
     pub breaking: bool,
+    /// What marked the commit as breaking, if it is
+    pub breaking_source: Option<BreakingSource>,
     pub issues: EcoVec<u64>,
+    /// Issues closed through a keyword such as `Closes #12` (also in `issues`)
+    pub closed_issues: EcoVec<u64>,
+    /// GitLab merge requests referenced as `!34`
+    pub merge_requests: EcoVec<u64>,
     pub co_authors: EcoVec<EcoString>,
+    /// Pull request number referenced by a squash-merge summary (`... (#123)`)
+    pub pr: Option<u64>,
+    /// Labels of the referenced pull request (populated when `fetch_pr_labels` is enabled)
+    pub labels: EcoVec<EcoString>,
+    /// Whether the summary follows the conventional commit header format
+    pub conventional: bool,
+    /// Upgrade instructions from the configured migration footer
+    pub migration: Option<EcoString>,
+    /// SHA of the commit this one reverts (git's `This reverts commit <sha>.`)
+    pub reverts: Option<EcoString>,
     pub type_cfg: Option<TypeConfigResolved>,
     /// Original chronological order position for deterministic ordering
     pub index: usize,
@@ -28,11 +47,13 @@ pub struct ParsedCommit {
 ///
 /// Determines how the version number should be incremented based on
 /// conventional commit types and breaking changes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum BumpKind {
     Major,
     Minor,
     Patch,
+    #[default]
     None,
 }
 
@@ -46,12 +67,24 @@ impl BumpKind {
             (None, None) => None,
         }
     }
+
+    /// Lowercase name, as serialized.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Patch => "patch",
+            Self::None => "none",
+        }
+    }
 }
 
 /// Parse and classify commits using either sequential or parallel processing.
 ///
 /// Automatically chooses between sequential and parallel processing based on
 /// the number of commits and the `NOVALYN_PARALLEL_THRESHOLD` environment variable.
+/// Parallel runs split the input into chunks of `NOVALYN_PARALLEL_CHUNK` commits
+/// (default [`DEFAULT_PARALLEL_CHUNK`]).
 ///
 /// # Arguments
 /// * `commits` - Raw commits from git repository
@@ -69,7 +102,11 @@ pub fn parse_and_classify(
         .unwrap_or(50);
 
     if commits.len() >= threshold {
-        parse_and_classify_parallel(commits, cfg)
+        let chunk_size = std::env::var("NOVALYN_PARALLEL_CHUNK")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_PARALLEL_CHUNK);
+        parse_and_classify_chunked(&commits, cfg, chunk_size)
     } else {
         parse_and_classify_sequential(commits, cfg)
     }
@@ -89,10 +126,10 @@ fn parse_and_classify_sequential(
     );
     use crate::utils::process_indexed;
     process_indexed(commits.into_iter().enumerate(), |idx, rc| {
-        let mut p = parse_one(&rc);
+        let mut p = parse_one(&rc, cfg);
         p.index = idx;
         classify(&mut p, cfg);
-        if should_keep(&p) {
+        if should_keep(&p, cfg) {
             tracing::debug!(commit = %p.raw.short_id, r#type = %p.r#type, scope = ?p.scope, breaking = p.breaking, issues = ?p.issues, "classified");
             Some(p)
         } else {
@@ -101,40 +138,58 @@ fn parse_and_classify_sequential(
     })
 }
 
-/// Parse and classify commits in parallel using rayon.
+/// Commits per work unit of [`parse_and_classify_chunked`] by default.
+pub const DEFAULT_PARALLEL_CHUNK: usize = 64;
+
+/// Parse and classify commits in parallel, `chunk_size` commits per rayon task.
 ///
-/// Processes commits concurrently while preserving original chronological order.
-/// Each commit is parsed and classified independently, then sorted back by index.
-fn parse_and_classify_parallel(
-    commits: EcoVec<RawCommit>,
+/// Each chunk is processed in order on one thread and the chunk results are
+/// appended in input order into a preallocated buffer, so the output matches
+/// the sequential path without re-sorting. A `chunk_size` of 0 is treated as 1.
+pub fn parse_and_classify_chunked(
+    commits: &[RawCommit],
     cfg: &ResolvedConfig,
+    chunk_size: usize,
 ) -> EcoVec<ParsedCommit> {
-    tracing::debug!(count = commits.len(), mode = "parallel", "parsing_commits");
-
-    let indexed_commits: Vec<(usize, RawCommit)> = commits.into_iter().enumerate().collect();
-    let mut parsed: EcoVec<ParsedCommit> = indexed_commits
-        .par_iter()
-        .map(|(idx, rc)| {
-            let mut p = parse_one(rc);
-            p.index = *idx;
-            classify(&mut p, cfg);
-            p
+    let chunk_size = chunk_size.max(1);
+    tracing::debug!(
+        count = commits.len(),
+        chunk_size,
+        mode = "parallel",
+        "parsing_commits"
+    );
+
+    let chunks: Vec<Vec<ParsedCommit>> = commits
+        .par_chunks(chunk_size)
+        .enumerate()
+        .map(|(chunk, rcs)| {
+            let base = chunk * chunk_size;
+            rcs.iter()
+                .enumerate()
+                .filter_map(|(offset, rc)| {
+                    let mut p = parse_one(rc, cfg);
+                    p.index = base + offset;
+                    classify(&mut p, cfg);
+                    should_keep(&p, cfg).then_some(p)
+                })
+                .collect()
         })
-        .filter(should_keep)
-        .collect::<Vec<_>>()
-        .into();
-    // Sort back to original chronological order
-    parsed.make_mut().sort_by_key(|p| p.index);
+        .collect();
+
+    let mut parsed = EcoVec::with_capacity(chunks.iter().map(Vec::len).sum());
+    for chunk in chunks {
+        parsed.extend(chunk);
+    }
     parsed
 }
 
 /// Parse a single raw commit using our ultra-fast zero-copy parser.
 ///
-/// Delegates to the optimized `parse_commit_fast` function for actual parsing,
+/// Delegates to the optimized `parse_commit_with` function for actual parsing,
 /// then wraps the result in a `ParsedCommit` with metadata.
 #[inline]
-fn parse_one(rc: &RawCommit) -> ParsedCommit {
-    let parsed = parse_commit_fast(rc);
+fn parse_one(rc: &RawCommit, cfg: &ResolvedConfig) -> ParsedCommit {
+    let parsed = parse_commit_with(rc, &parse_options(cfg));
 
     ParsedCommit {
         raw: rc.clone(),
@@ -144,44 +199,201 @@ fn parse_one(rc: &RawCommit) -> ParsedCommit {
         body: parsed.body,
         footers: parsed.footers,
         breaking: parsed.breaking,
+        breaking_source: parsed.breaking_source,
         issues: parsed.issues,
+        closed_issues: parsed.closed_issues,
+        merge_requests: parsed.merge_requests,
         co_authors: parsed.co_authors,
+        pr: parsed.pr,
+        labels: EcoVec::new(),
+        conventional: parsed.conventional,
+        migration: None,
+        reverts: parsed.reverts,
         type_cfg: None,
         index: 0,
     }
 }
 
+/// Header parsing options derived from the configuration.
+fn parse_options(cfg: &ResolvedConfig) -> ParseOptions<'_> {
+    ParseOptions {
+        slash_scope: cfg.slash_scope,
+        co_author_keys: &cfg.co_author_keys,
+        merge_requests: cfg
+            .repo
+            .as_ref()
+            .is_some_and(|r| r.provider == Provider::GitLab),
+    }
+}
+
+/// Commits whose header uses one of the configured `banned_scopes`.
+///
+/// Scopes are checked as written, before `scope_map` and before any commit
+/// filtering, and each scope of a multi-scope header (`feat(api,internal): ...`)
+/// is checked on its own.
+pub fn banned_scope_commits<'a>(
+    commits: &'a [RawCommit],
+    cfg: &ResolvedConfig,
+) -> Vec<&'a RawCommit> {
+    if cfg.banned_scopes.is_empty() {
+        return Vec::new();
+    }
+    let opts = parse_options(cfg);
+    commits
+        .iter()
+        .filter(|rc| {
+            parse_commit_with(rc, &opts).scope.is_some_and(|scope| {
+                scope
+                    .split(',')
+                    .map(str::trim)
+                    .any(|part| cfg.banned_scopes.iter().any(|banned| banned == part))
+            })
+        })
+        .collect()
+}
+
+/// The `scope_map` replacement for `scope` (exact match first, then regex
+/// entries); an empty string clears the scope.
+fn map_scope(cfg: &ResolvedConfig, scope: &str) -> Option<EcoString> {
+    cfg.scope_map.get(scope).cloned().or_else(|| {
+        cfg.scope_patterns
+            .iter()
+            .find(|(re, _)| re.is_match(scope))
+            .map(|(re, rep)| EcoString::from(re.replace(scope, rep.as_str()).as_ref()))
+    })
+}
+
 /// Classify a parsed commit by matching its type against configured types.
 ///
-/// Sets the `type_cfg` field if a matching type is found in the configuration.
+/// Sets the `type_cfg` field if a matching type is found in the configuration,
+/// including disabled types so [`should_keep`] can tell them apart.
 fn classify(pc: &mut ParsedCommit, cfg: &ResolvedConfig) {
-    // Apply scope_map if provided (exact match)
-    if let Some(sc) = &mut pc.scope
-        && let Some(mapped) = cfg.scope_map.get(sc)
-    {
-        if mapped.is_empty() {
-            pc.scope = None;
+    // Apply scope_map if provided; each scope of a multi-scope header
+    // (`feat(api,ui): ...`) is mapped on its own unless the whole scope matches
+    if let Some(sc) = &pc.scope {
+        let mapped = map_scope(cfg, sc).or_else(|| {
+            sc.contains(',').then(|| {
+                let parts: Vec<EcoString> = sc
+                    .split(',')
+                    .map(str::trim)
+                    .map(|part| map_scope(cfg, part).unwrap_or_else(|| part.into()))
+                    .filter(|part| !part.is_empty())
+                    .collect();
+                parts.join(",").into()
+            })
+        });
+        if let Some(mapped) = mapped {
+            pc.scope = (!mapped.is_empty()).then_some(mapped);
+        }
+    }
+    let ty = resolve_type(pc, cfg);
+    if ty != pc.r#type {
+        if pc.conventional {
+            pc.r#type = ty;
         } else {
-            *sc = mapped.clone();
+            assign_type(pc, ty, cfg);
         }
     }
-    if let Some(tc) = cfg.types.iter().find(|t| t.key == pc.r#type)
-        && tc.enabled
-    {
+    if let Some(tc) = cfg.types.iter().find(|t| t.key == pc.r#type) {
         pc.type_cfg = Some(tc.clone());
     }
+    if !cfg.migration_footer.is_empty() {
+        pc.migration = pc
+            .footers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(&cfg.migration_footer))
+            .map(|(_, v)| v.clone());
+    }
+    if pc.description.is_empty() && cfg.on_empty_description == OnEmptyDescription::Summary {
+        pc.description = pc.raw.summary.trim().into();
+    }
+}
+
+/// The type a commit is classified under, taken from the first rule that applies:
+///
+/// 1. a `category_footer` footer (`Changelog-Category: docs`)
+/// 2. a `type_aliases` entry for the parsed type (`feature` gives `feat`)
+/// 3. a `keyword_types` entry for the first summary word, for commits
+///    without a conventional header (`Fixed crash` gives `fix`)
+/// 4. the parsed type itself
+pub fn resolve_type(pc: &ParsedCommit, cfg: &ResolvedConfig) -> EcoString {
+    if !cfg.category_footer.is_empty()
+        && let Some((_, category)) = pc
+            .footers
+            .iter()
+            .find(|(k, v)| k.eq_ignore_ascii_case(&cfg.category_footer) && !v.trim().is_empty())
+    {
+        return category.trim().to_lowercase().into();
+    }
+    if let Some(ty) = cfg.type_aliases.get(&pc.r#type) {
+        return ty.clone();
+    }
+    if !pc.conventional
+        && let Some(ty) = summary_keyword(&pc.raw.summary).and_then(|kw| cfg.keyword_types.get(&kw))
+    {
+        return ty.clone();
+    }
+    pc.r#type.clone()
+}
+
+/// First word of a summary, lowercased and without trailing punctuation, as
+/// matched against `keyword_types` (`"Fixed: crash"` gives `fixed`).
+pub fn summary_keyword(summary: &str) -> Option<EcoString> {
+    let word = summary
+        .split_whitespace()
+        .next()?
+        .trim_end_matches(|c: char| !c.is_alphanumeric());
+    (!word.is_empty()).then(|| word.to_lowercase().into())
+}
+
+/// Give a non-conventional commit the type `ty`.
+///
+/// Its header was never parsed, so the whole summary becomes the description.
+pub fn assign_type(pc: &mut ParsedCommit, ty: EcoString, cfg: &ResolvedConfig) {
+    pc.type_cfg = cfg.types.iter().find(|t| t.key == ty).cloned();
+    pc.r#type = ty;
+    pc.description = pc.raw.summary.trim().into();
 }
 
 /// Determine if a parsed commit should be kept in the changelog.
 ///
-/// Commits are kept if they have a valid type configuration.
-fn should_keep(pc: &ParsedCommit) -> bool {
+/// Commits are dropped when their message contains the configured skip
+/// marker, their summary matches a `skip_commit_patterns` regex, or they are
+/// release commits made by automation (`release_commit_template`). Commits of
+/// disabled types and (with `filter_chore_deps`) dependency chores are dropped
+/// unless they are breaking.
+fn should_keep(pc: &ParsedCommit, cfg: &ResolvedConfig) -> bool {
+    if pc.description.is_empty() && cfg.on_empty_description == OnEmptyDescription::Skip {
+        tracing::warn!(commit = %pc.raw.short_id, summary = %pc.raw.summary, "skipping commit with empty description");
+        return false;
+    }
+    if !cfg.skip_marker.is_empty()
+        && (pc.raw.summary.contains(cfg.skip_marker.as_str())
+            || pc.raw.body.contains(cfg.skip_marker.as_str()))
+    {
+        return false;
+    }
+    if cfg
+        .skip_commit_patterns
+        .iter()
+        .any(|re| re.is_match(&pc.raw.summary))
+    {
+        return false;
+    }
+    if let Some(re) = &cfg.release_commit_pattern
+        && re.is_match(pc.raw.summary.trim())
+    {
+        return false;
+    }
+    // Disabled types are hidden from the changelog, but breaking commits stay
+    // in the set so they still drive version inference
     if let Some(tc) = &pc.type_cfg
         && !tc.enabled
+        && !pc.breaking
     {
         return false;
     }
-    if pc.r#type == "chore" && !pc.breaking {
+    if cfg.filter_chore_deps && pc.r#type == "chore" && !pc.breaking {
         // Filter dependency update chores: chore(deps), chore(deps-dev), chore(deps-*) etc.
         // Accept if not starting with chore(deps because there may be other chore scopes we keep
         let lower = pc.raw.summary.to_ascii_lowercase();
@@ -192,41 +404,120 @@ fn should_keep(pc: &ParsedCommit) -> bool {
     true
 }
 
+/// The version bump a single commit contributes on its own.
+///
+/// Breaking changes are always major; otherwise the impact of the commit's
+/// configured type applies, and unclassified commits have no impact.
+pub fn commit_impact(c: &ParsedCommit) -> BumpKind {
+    if c.breaking {
+        return BumpKind::Major;
+    }
+    match c.type_cfg.as_ref().map(|tc| tc.semver) {
+        Some(SemverImpact::Major) => BumpKind::Major,
+        Some(SemverImpact::Minor) => BumpKind::Minor,
+        Some(SemverImpact::Patch) => BumpKind::Patch,
+        Some(SemverImpact::None) | None => BumpKind::None,
+    }
+}
+
+/// Policy knobs for [`infer_version_with`].
+#[derive(Debug, Clone, Default)]
+pub struct InferOptions {
+    /// How minor-impact commits bump a 0.x version
+    pub zero_major_feat: ZeroMajorFeat,
+    /// Smallest bump applied when there are commits (before the 0.x rules)
+    pub min_bump: BumpKind,
+    /// Produce a prerelease with this identifier format (`{{n}}` is the counter)
+    pub prerelease: Option<EcoString>,
+}
+
+/// Result of version inference along with how each commit contributed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionDecision {
+    /// The next version
+    pub version: semver::Version,
+    /// Bump applied to reach `version` (after the 0.x rules)
+    pub bump: BumpKind,
+    /// `(ParsedCommit::index, impact)` of every commit, in input order
+    pub impacts: Vec<(usize, BumpKind)>,
+}
+
 pub fn infer_version(
     previous: &semver::Version,
     commits: &[ParsedCommit],
     override_new: Option<semver::Version>,
 ) -> (semver::Version, BumpKind) {
-    if let Some(v) = override_new {
-        return (v, BumpKind::None);
-    }
-    if commits.is_empty() {
+    infer_version_with(previous, commits, override_new, &InferOptions::default())
+}
+
+/// Infer the next version like [`infer_version`], applying the given policy.
+pub fn infer_version_with(
+    previous: &semver::Version,
+    commits: &[ParsedCommit],
+    override_new: Option<semver::Version>,
+    opts: &InferOptions,
+) -> (semver::Version, BumpKind) {
+    let decision = infer_version_detailed_with(previous, commits, override_new, opts);
+    (decision.version, decision.bump)
+}
+
+/// Infer the next version like [`infer_version`], keeping per-commit impacts.
+pub fn infer_version_detailed(
+    previous: &semver::Version,
+    commits: &[ParsedCommit],
+    override_new: Option<semver::Version>,
+) -> VersionDecision {
+    infer_version_detailed_with(previous, commits, override_new, &InferOptions::default())
+}
+
+/// Infer the next version like [`infer_version_with`], keeping per-commit impacts.
+pub fn infer_version_detailed_with(
+    previous: &semver::Version,
+    commits: &[ParsedCommit],
+    override_new: Option<semver::Version>,
+    opts: &InferOptions,
+) -> VersionDecision {
+    let impacts: Vec<(usize, BumpKind)> = commits
+        .iter()
+        .map(|c| (c.index, commit_impact(c)))
+        .collect();
+    let (version, bump) = match override_new {
+        Some(v) => (v, BumpKind::None),
         // No commits at all -> treat as no change (idempotent rerun)
-        return (previous.clone(), BumpKind::None);
+        None if commits.is_empty() => (previous.clone(), BumpKind::None),
+        None => bump_version(previous, &impacts, opts),
+    };
+    VersionDecision {
+        version,
+        bump,
+        impacts,
     }
+}
+
+/// Apply the strongest of `impacts` to `previous` under the given policy.
+///
+/// A prerelease `previous` is bumped through its version core: when the core
+/// already covers the bump (see [`core_bump`]) the core itself is released,
+/// otherwise the core is bumped as if it were stable. The returned kind is the
+/// bump the new version represents.
+fn bump_version(
+    previous: &semver::Version,
+    impacts: &[(usize, BumpKind)],
+    opts: &InferOptions,
+) -> (semver::Version, BumpKind) {
     use BumpKind::*;
-    let mut impact = BumpKind::None;
-    for c in commits {
-        let bump = if c.breaking {
-            Major
-        } else if let Some(tc) = &c.type_cfg {
-            match tc.semver {
-                SemverImpact::Major => Major,
-                SemverImpact::Minor => Minor,
-                SemverImpact::Patch => Patch,
-                SemverImpact::None => None,
-            }
-        } else {
-            None
-        };
-        impact = impact.escalate(bump);
+    let mut impact = opts.min_bump;
+    for (_, commit) in impacts {
+        impact = impact.escalate(*commit);
     }
     let mut new = previous.clone();
+    new.pre = semver::Prerelease::EMPTY;
     match impact {
         Major => {
             if previous.major == 0 {
                 new.minor += 1;
                 new.patch = 0;
+                impact = Minor; // degrade classification for reporting
             } else {
                 new.major += 1;
                 new.minor = 0;
@@ -234,7 +525,7 @@ pub fn infer_version(
             }
         }
         Minor => {
-            if previous.major == 0 {
+            if previous.major == 0 && opts.zero_major_feat == ZeroMajorFeat::Patch {
                 new.patch += 1;
                 impact = Patch; // degrade classification for reporting
             } else {
@@ -248,26 +539,308 @@ pub fn infer_version(
         None => {
             // No impactful commits => still bump patch (default policy)
             new.patch += 1;
-            return (new, Patch);
+            impact = Patch;
+        }
+    }
+    if !previous.pre.is_empty() {
+        let pending = core_bump(previous);
+        if pending.escalate(impact) == pending {
+            new = semver::Version::new(previous.major, previous.minor, previous.patch);
+            impact = pending;
         }
     }
+    if let Some(format) = &opts.prerelease {
+        new = next_prerelease(previous, new, format);
+    }
     (new, impact)
 }
 
+/// The bump a prerelease's version core stands for: `1.2.3-rc.1` is a patch,
+/// `1.3.0-rc.1` a minor and `2.0.0-rc.1` a major release.
+fn core_bump(version: &semver::Version) -> BumpKind {
+    if version.patch > 0 {
+        BumpKind::Patch
+    } else if version.minor > 0 {
+        BumpKind::Minor
+    } else {
+        BumpKind::Major
+    }
+}
+
+/// Expand a prerelease identifier `format` for counter `n`.
+///
+/// Returns `None` when the result is not a valid semver prerelease.
+pub fn prerelease_identifier(format: &str, n: u64) -> Option<semver::Prerelease> {
+    let id = format.replace("{{n}}", &n.to_string());
+    if id.is_empty() {
+        return None;
+    }
+    semver::Prerelease::new(&id).ok()
+}
+
+/// Next prerelease of the stable version `bumped` that inference produced.
+///
+/// When `previous` is a prerelease of the same version core, its counter is
+/// continued if its identifier matches `format`. The counter otherwise starts
+/// at 1.
+fn next_prerelease(
+    previous: &semver::Version,
+    bumped: semver::Version,
+    format: &str,
+) -> semver::Version {
+    let same_core = !previous.pre.is_empty()
+        && (previous.major, previous.minor, previous.patch)
+            == (bumped.major, bumped.minor, bumped.patch);
+    let n = if same_core {
+        let (prefix, suffix) = format.split_once("{{n}}").unwrap_or((format, ""));
+        previous
+            .pre
+            .as_str()
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix))
+            .and_then(|n| n.parse::<u64>().ok())
+            .map_or(1, |n| n + 1)
+    } else {
+        1
+    };
+    let mut next = bumped;
+    next.pre = prerelease_identifier(format, n).unwrap_or(semver::Prerelease::EMPTY);
+    next.build = semver::BuildMetadata::EMPTY;
+    next
+}
+
+/// Set the package version in `path/Cargo.toml`, keeping its formatting.
+///
+/// A package inheriting its version (`version.workspace = true`) is bumped
+/// through the `[workspace.package]` version of the enclosing workspace, which
+/// also bumps every other member inheriting it. A `[workspace.package]` version
+/// in `path/Cargo.toml` itself is updated too. Afterwards, the version
+/// requirements of path dependencies on the bumped packages are updated across
+/// the workspace so they keep resolving.
+///
+/// # Returns
+/// Names of the packages whose version changed
 pub fn bump_cargo_version(
     path: &std::path::Path,
     new_version: &semver::Version,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Vec<EcoString>> {
+    let version = new_version.to_string();
+    let mut doc = read_manifest(path)?;
+    let mut bumped: Vec<EcoString> = Vec::new();
+    let mut inherits = false;
+    if let Some(pkg) = doc.get_mut("package") {
+        let name = pkg
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(EcoString::from);
+        match pkg.get_mut("version") {
+            Some(ver) if ver.is_str() => {
+                set_version(ver, &version);
+                bumped.extend(name);
+            }
+            Some(ver) => {
+                inherits = ver.get("workspace").and_then(|w| w.as_bool()) == Some(true);
+            }
+            None => {}
+        }
+    }
+    let mut workspace_bumped = bump_workspace_package(&mut doc, &version);
+    std::fs::write(path.join("Cargo.toml"), doc.to_string())?;
+
+    // Manifests may sit in an enclosing workspace rooted above `path`
+    let root = path
+        .ancestors()
+        .find(|dir| read_manifest(dir).is_ok_and(|doc| doc.contains_key("workspace")));
+    let Some(root) = root else {
+        return Ok(bumped);
+    };
+    if inherits && root != path {
+        let mut root_doc = read_manifest(root)?;
+        if bump_workspace_package(&mut root_doc, &version) {
+            std::fs::write(root.join("Cargo.toml"), root_doc.to_string())?;
+            workspace_bumped = true;
+        }
+    }
+
+    let mut manifests = vec![root.to_path_buf()];
+    manifests.extend(crate::workspace::members(root)?);
+    manifests.dedup();
+    if workspace_bumped {
+        for dir in &manifests {
+            let doc = read_manifest(dir)?;
+            let Some(pkg) = doc.get("package") else {
+                continue;
+            };
+            let inherited = pkg
+                .get("version")
+                .and_then(|v| v.get("workspace"))
+                .and_then(|w| w.as_bool())
+                == Some(true);
+            if inherited && let Some(name) = pkg.get("name").and_then(|n| n.as_str()) {
+                bumped.push(name.into());
+            }
+        }
+    }
+    bumped.sort();
+    bumped.dedup();
+
+    for dir in &manifests {
+        let mut doc = read_manifest(dir)?;
+        if bump_path_dependencies(&mut doc, &bumped, &version) {
+            std::fs::write(dir.join("Cargo.toml"), doc.to_string())?;
+        }
+    }
+    Ok(bumped)
+}
+
+/// Whether the package in `path/Cargo.toml` inherits its version from the
+/// workspace (`version.workspace = true`).
+pub fn inherits_workspace_version(path: &std::path::Path) -> anyhow::Result<bool> {
+    let doc = read_manifest(path)?;
+    Ok(doc
+        .get("package")
+        .and_then(|p| p.get("version"))
+        .and_then(|v| v.get("workspace"))
+        .and_then(|w| w.as_bool())
+        == Some(true))
+}
+
+fn read_manifest(dir: &std::path::Path) -> anyhow::Result<toml_edit::DocumentMut> {
     use anyhow::Context;
-    let txt = std::fs::read_to_string(path.join("Cargo.toml"))?;
-    let mut doc: toml_edit::DocumentMut = txt.parse().context("parse Cargo.toml")?;
-    if let Some(pkg) = doc.get_mut("package")
-        && let Some(ver) = pkg.get_mut("version")
+    let path = dir.join("Cargo.toml");
+    let txt = std::fs::read_to_string(&path).with_context(|| format!("Reading {path:?}"))?;
+    txt.parse()
+        .with_context(|| format!("Parsing TOML {path:?}"))
+}
+
+/// Replace a version string, keeping the surrounding whitespace and comments.
+fn set_version(item: &mut toml_edit::Item, version: &str) {
+    let decor = item.as_value().map(|v| v.decor().clone());
+    *item = toml_edit::value(version);
+    if let (Some(decor), Some(value)) = (decor, item.as_value_mut()) {
+        *value.decor_mut() = decor;
+    }
+}
+
+/// Set `[workspace.package] version` if present.
+fn bump_workspace_package(doc: &mut toml_edit::DocumentMut, version: &str) -> bool {
+    match doc
+        .get_mut("workspace")
+        .and_then(|w| w.get_mut("package"))
+        .and_then(|p| p.get_mut("version"))
     {
-        *ver = toml_edit::value(new_version.to_string());
+        Some(ver) if ver.is_str() => {
+            set_version(ver, version);
+            true
+        }
+        _ => false,
     }
-    std::fs::write(path.join("Cargo.toml"), doc.to_string())?;
-    Ok(())
+}
+
+/// Update `version` requirements of path dependencies on `names`, keeping
+/// their operator (e.g. `=`).
+fn bump_path_dependencies(
+    doc: &mut toml_edit::DocumentMut,
+    names: &[EcoString],
+    version: &str,
+) -> bool {
+    const KINDS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+    let table = doc.as_table_mut();
+    let mut tables: Vec<&mut dyn toml_edit::TableLike> = Vec::new();
+    for (key, item) in table.iter_mut() {
+        match key.get() {
+            kind if KINDS.contains(&kind) => tables.extend(item.as_table_like_mut()),
+            "workspace" => {
+                tables.extend(
+                    item.get_mut("dependencies")
+                        .and_then(|d| d.as_table_like_mut()),
+                );
+            }
+            "target" => {
+                for (_, target) in item
+                    .as_table_like_mut()
+                    .into_iter()
+                    .flat_map(|t| t.iter_mut())
+                {
+                    for (kind, deps) in target
+                        .as_table_like_mut()
+                        .into_iter()
+                        .flat_map(|t| t.iter_mut())
+                    {
+                        if KINDS.contains(&kind.get()) {
+                            tables.extend(deps.as_table_like_mut());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut changed = false;
+    for deps in tables {
+        for (key, dep) in deps.iter_mut() {
+            let Some(dep) = dep.as_table_like_mut() else {
+                continue;
+            };
+            let package = dep
+                .get("package")
+                .and_then(|p| p.as_str())
+                .unwrap_or(key.get());
+            if !dep.contains_key("path") || !names.iter().any(|n| n == package) {
+                continue;
+            }
+            if let Some(req) = dep.get_mut("version")
+                && let Some(old) = req.as_str()
+            {
+                let op_len = old.len() - old.trim_start_matches(['=', '^', '~', ' ']).len();
+                let new = format!("{}{version}", &old[..op_len]);
+                if new != old {
+                    set_version(req, &new);
+                    changed = true;
+                }
+            }
+        }
+    }
+    changed
+}
+
+/// Set the version of package `name` in the `Cargo.lock` nearest to `path`.
+///
+/// The lockfile is looked up in `path` and its parents (workspace root). Only
+/// the local `[[package]]` entry (no `source`) called `name` is changed.
+///
+/// # Returns
+/// `true` if a lockfile entry was updated, `false` if none was found
+pub fn bump_cargo_lock(
+    path: &std::path::Path,
+    name: &str,
+    new_version: &semver::Version,
+) -> anyhow::Result<bool> {
+    use anyhow::Context;
+    let Some(lock_path) = path
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|p| p.exists())
+    else {
+        return Ok(false);
+    };
+    let txt = std::fs::read_to_string(&lock_path)?;
+    let mut doc: toml_edit::DocumentMut = txt.parse().context("parse Cargo.lock")?;
+    let Some(packages) = doc
+        .get_mut("package")
+        .and_then(|p| p.as_array_of_tables_mut())
+    else {
+        return Ok(false);
+    };
+    let Some(entry) = packages.iter_mut().find(|pkg| {
+        pkg.get("name").and_then(|n| n.as_str()) == Some(name) && !pkg.contains_key("source")
+    }) else {
+        return Ok(false);
+    };
+    entry["version"] = toml_edit::value(new_version.to_string());
+    std::fs::write(&lock_path, doc.to_string())?;
+    Ok(true)
 }
 
 /// Interpolate template variables in a string.
@@ -276,6 +849,9 @@ pub fn bump_cargo_version(
 /// - `{{from}}` - Previous version
 /// - `{{to}}` - New version  
 /// - `{{date}}` - Release date in ISO format
+/// - `{{env.VAR}}` - Value of environment variable `VAR` (empty if unset)
+///
+/// Unknown tokens are left as they are.
 ///
 /// # Arguments
 /// * `template` - Template string with placeholders
@@ -320,7 +896,10 @@ pub fn interpolate(
                 "newVersion" => new_version.to_string(),
                 "previousVersion" => previous.to_string(),
                 "date" => format!("{}-{:02}-{:02}", date.year(), date.month(), date.day()),
-                _ => format!("{{{{{}}}}}", key),
+                _ => match key.strip_prefix("env.") {
+                    Some(var) => std::env::var(var).unwrap_or_default(),
+                    None => format!("{{{{{}}}}}", key),
+                },
             };
             out.push_str(&rep);
         } else {
@@ -341,6 +920,44 @@ mod tests {
         assert_eq!(kind, BumpKind::None);
     }
     #[test]
+    fn detailed_decision_lists_commit_impacts() {
+        let types = crate::config::default_types();
+        let commit = |index: usize, ty: &str, breaking: bool| ParsedCommit {
+            r#type: ty.into(),
+            breaking,
+            type_cfg: types.iter().find(|t| t.key == ty).cloned(),
+            index,
+            ..Default::default()
+        };
+        let commits = [
+            commit(0, "fix", false),
+            commit(1, "feat", false),
+            commit(2, "docs", false),
+            commit(3, "fix", true),
+            commit(4, "feat", false),
+            commit(5, "wip", false),
+        ];
+        let prev = semver::Version::parse("1.4.2").unwrap();
+        let decision = infer_version_detailed(&prev, &commits, None);
+        assert_eq!(decision.version.to_string(), "2.0.0");
+        assert_eq!(decision.bump, BumpKind::Major);
+        assert_eq!(
+            decision.impacts,
+            [
+                (0, BumpKind::Patch),
+                (1, BumpKind::Minor),
+                (2, BumpKind::None),
+                (3, BumpKind::Major),
+                (4, BumpKind::Minor),
+                (5, BumpKind::None),
+            ]
+        );
+        assert_eq!(
+            infer_version(&prev, &commits, None),
+            (decision.version, decision.bump)
+        );
+    }
+    #[test]
     fn idempotent_same_version_no_change() {
         let prev = semver::Version::parse("1.2.3").unwrap();
         // No commits -> same version (no change)
@@ -348,4 +965,65 @@ mod tests {
         assert_eq!(v.to_string(), "1.2.3");
         assert_eq!(kind, BumpKind::None);
     }
+    #[test]
+    fn workspace_version_and_path_deps_bumped() {
+        let td = tempfile::tempdir().unwrap();
+        let root = td.path();
+        let write = |rel: &str, txt: &str| {
+            std::fs::create_dir_all(root.join(rel)).unwrap();
+            std::fs::write(root.join(rel).join("Cargo.toml"), txt).unwrap();
+        };
+        let read = |rel: &str| std::fs::read_to_string(root.join(rel).join("Cargo.toml")).unwrap();
+        write(
+            "",
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"0.1.0\" # shared\n\n[workspace.dependencies]\na = { path = \"crates/a\", version = \"0.1.0\" }\nserde = \"1\"\n",
+        );
+        write(
+            "crates/a",
+            "[package]\nname = \"a\"\nversion.workspace = true\n",
+        );
+        write(
+            "crates/b",
+            "[package]\nname = \"b\"\nversion = { workspace = true }\n\n[dependencies]\na = { path = \"../a\", version = \"=0.1.0\" }\nother = { version = \"0.1.0\" }\n",
+        );
+
+        let version = semver::Version::parse("0.2.0").unwrap();
+        let bumped = bump_cargo_version(&root.join("crates/a"), &version).unwrap();
+        assert_eq!(bumped, ["a", "b"]);
+        assert_eq!(
+            read(""),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"0.2.0\" # shared\n\n[workspace.dependencies]\na = { path = \"crates/a\", version = \"0.2.0\" }\nserde = \"1\"\n"
+        );
+        // Inheritance is kept rather than replaced with a literal version
+        assert_eq!(
+            read("crates/a"),
+            "[package]\nname = \"a\"\nversion.workspace = true\n"
+        );
+        assert_eq!(
+            read("crates/b"),
+            "[package]\nname = \"b\"\nversion = { workspace = true }\n\n[dependencies]\na = { path = \"../a\", version = \"=0.2.0\" }\nother = { version = \"0.1.0\" }\n"
+        );
+    }
+    #[test]
+    fn cargo_lock_entry_follows_bump() {
+        let td = tempfile::tempdir().unwrap();
+        std::fs::write(
+            td.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        let lock = "version = 4\n\n[[package]]\nname = \"demo\"\nversion = \"0.1.0\"\ndependencies = [\n \"demo 0.3.0\",\n]\n\n[[package]]\nname = \"demo\"\nversion = \"0.3.0\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n";
+        std::fs::write(td.path().join("Cargo.lock"), lock).unwrap();
+        let version = semver::Version::parse("0.2.0").unwrap();
+        bump_cargo_version(td.path(), &version).unwrap();
+        let name = crate::config::crate_name(td.path()).unwrap();
+        assert!(bump_cargo_lock(td.path(), &name, &version).unwrap());
+        let updated = std::fs::read_to_string(td.path().join("Cargo.lock")).unwrap();
+        assert_eq!(
+            updated,
+            lock.replacen("version = \"0.1.0\"", "version = \"0.2.0\"", 1)
+        );
+        let manifest = std::fs::read_to_string(td.path().join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("version = \"0.2.0\""));
+    }
 }
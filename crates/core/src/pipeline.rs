@@ -1,14 +1,19 @@
 use crate::{
-    authors::{AuthorOptions, Authors},
+    authors::{AuthorOptions, Authors, AuthorsFile, DEFAULT_HANDLE_CACHE_TTL, HandleCache},
     changelog,
-    config::{self, LoadOptions},
-    git, parse,
-    render::{RenderContext, render_release_block},
+    config::{self, LoadOptions, PreviousTagStrategy, RawConfig, ResolvedConfig},
+    error::NovalynError,
+    git, github, parse,
+    render::{BlockFormat, RenderContext, render_release_block_as},
+    report::Report,
+    repository::Provider,
+    workspace,
 };
 
 use anyhow::Result;
-use demand::Confirm;
+use demand::{Confirm, DemandOption, Select};
 use ecow::{EcoString, EcoVec};
+use std::io::IsTerminal;
 use tracing::{debug, info, instrument, warn};
 
 /// Interactive confirmation prompt for release operations.
@@ -42,6 +47,47 @@ fn confirm_action(message: &str, yes_flag: bool) -> Result<bool> {
     }
 }
 
+/// Prompt for a type for each non-conventional commit that no configured
+/// type or `keyword_types` entry matched, applying the choices.
+///
+/// # Returns
+/// The `(summary keyword, type)` pairs chosen, for saving to `keyword_types`
+fn assign_types_interactively(
+    commits: &mut EcoVec<parse::ParsedCommit>,
+    cfg: &ResolvedConfig,
+) -> Result<Vec<(EcoString, EcoString)>> {
+    let mut chosen = Vec::new();
+    for c in commits
+        .make_mut()
+        .iter_mut()
+        .filter(|c| !c.conventional && c.type_cfg.is_none())
+    {
+        let mut select = Select::new(format!("Type for {} {}", c.raw.short_id, c.raw.summary))
+            .option(DemandOption::with_label("skip", None));
+        for tc in cfg.types.iter().filter(|tc| tc.enabled) {
+            select = select.option(DemandOption::with_label(
+                format!("{} ({})", tc.key, tc.title),
+                Some(tc.key.clone()),
+            ));
+        }
+        match select.run() {
+            Ok(Some(ty)) => {
+                if let Some(keyword) = parse::summary_keyword(&c.raw.summary) {
+                    chosen.push((keyword, ty.clone()));
+                }
+                parse::assign_type(c, ty, cfg);
+            }
+            Ok(None) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {
+                tracing::info!("Type assignment cancelled by user");
+                break;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(chosen)
+}
+
 /// Exit codes returned by release pipeline.
 ///
 /// Following standard Unix conventions for process exit codes.
@@ -58,6 +104,7 @@ pub enum ExitCode {
 ///
 /// Controls all aspects of changelog generation, version bumping,
 /// and git operations.
+#[derive(Debug, Clone, Default)]
 pub struct ReleaseOptions {
     pub cwd: std::path::PathBuf,
     pub from: Option<EcoString>,
@@ -74,6 +121,54 @@ pub struct ReleaseOptions {
     pub github_alias: bool,
     /// GitHub API token for handle resolution
     pub github_token: Option<EcoString>,
+    /// Drop commits whose changes all fall under these paths (added to config `exclude_paths`)
+    pub exclude_paths: EcoVec<std::path::PathBuf>,
+    /// Exclude commits without a conventional header (also enabled by config)
+    pub strict_conventional: bool,
+    /// Write a JSON classification report to this path (relative to `cwd`)
+    pub report: Option<std::path::PathBuf>,
+    /// Only include commits touching these paths (relative to the repository root)
+    pub paths: EcoVec<std::path::PathBuf>,
+    /// Skip creating the release tag
+    pub no_tag: bool,
+    /// Omit the compare link even when config enables it
+    pub no_compare: bool,
+    /// Render without type emoji even when config enables them
+    pub no_emoji: bool,
+    /// Start the range at the merge-base of `to` and this branch (overrides `from`)
+    pub base: Option<EcoString>,
+    /// Prerelease identifier format overriding config `prerelease_format`
+    pub prerelease_format: Option<EcoString>,
+    /// Prerelease channel (e.g. "rc"), short for a `"<channel>.{{n}}"` format
+    pub prerelease: Option<EcoString>,
+    /// Previous-tag strategy used unless config sets `previous_tag_strategy`
+    pub default_previous_tag_strategy: Option<PreviousTagStrategy>,
+    /// Bump the package version in Cargo.toml and its Cargo.lock entry on release
+    pub update_lockfile: bool,
+    /// Write CHANGELOG.md even if it contains merge conflict markers
+    pub force: bool,
+    /// Workspace root whose config is layered below the config found in `cwd`
+    pub workspace_root: Option<std::path::PathBuf>,
+    /// Ignore `[package.metadata.novalyn]` in Cargo.toml
+    pub no_metadata: bool,
+    /// Reuse and record GitHub handle lookups in the on-disk cache
+    pub handle_cache: bool,
+    /// Changelog file to update, relative to `cwd` (default CHANGELOG.md)
+    pub output: Option<std::path::PathBuf>,
+    /// Backfill the block directly above this existing release (no manifest bump, tag or hooks)
+    pub insert_after: Option<semver::Version>,
+    /// Start from 0.0.0 rather than the Cargo.toml version when there is no tag
+    pub no_manifest_version: bool,
+    /// Run config `pre_release_hooks`/`post_release_hooks` (a dry run only lists them)
+    pub run_hooks: bool,
+    /// Prompt for a type for each non-conventional commit (TTY only, skipped with `yes`)
+    pub interactive: bool,
+    /// Record interactive choices in the `[keyword_types]` table of novalyn.toml
+    pub save_keyword_types: bool,
+    /// Only process the most recent N commits of the range
+    pub limit: Option<usize>,
+    /// Markup of the rendered block; anything but markdown requires `dry_run`
+    pub block_format: BlockFormat,
 }
 
 /// Result of a release pipeline execution.
@@ -83,11 +178,21 @@ pub struct ReleaseOptions {
 pub struct ReleaseOutcome {
     pub version: semver::Version,
     pub previous: Option<semver::Version>,
+    /// Bump inferred from the commits (`None` for an explicit version)
+    pub bump: parse::BumpKind,
     pub wrote: bool,
     pub changelog_path: std::path::PathBuf,
     pub commit_count: usize,
+    /// Commits left out because they are not conventional (strict mode only)
+    pub non_conventional: usize,
+    /// Rendered markdown release block
+    pub block: EcoString,
     /// Process exit code
     pub exit: ExitCode,
+    /// Configuration warnings (unknown keys, unrecognized remote, ...)
+    pub warnings: EcoVec<EcoString>,
+    /// Interpolated hook commands a dry run would have executed
+    pub skipped_hooks: EcoVec<EcoString>,
 }
 
 /// Execute the complete release pipeline asynchronously.
@@ -99,27 +204,71 @@ pub struct ReleaseOutcome {
 /// 4. Infer semantic version
 /// 5. Collect and resolve authors
 /// 6. Render changelog block
-/// 7. Write to CHANGELOG.md
-/// 8. Update Cargo.toml version
-/// 9. Create git commit and tag
+/// 7. Once the write is confirmed, run `pre_release_hooks` (with `opts.run_hooks`)
+/// 8. Write to CHANGELOG.md (or `opts.output`)
+/// 9. Update Cargo.toml version
+/// 10. Create git commit and tag
+/// 11. Run `post_release_hooks` (with `opts.run_hooks`)
 ///
 /// # Arguments
 /// * `opts` - Release configuration options
 ///
 /// # Returns
 /// * `Ok(ReleaseOutcome)` - Successful release with details
-/// * `Err` - Pipeline error occurred
+/// * `Err` - Pipeline error occurred, or a commit uses a banned scope
 ///
 /// # Errors
 /// Returns error if configuration loading, git operations, or file writes fail
 #[instrument(skip_all, fields(cwd = %opts.cwd.display()))]
 pub async fn run_release_async(opts: ReleaseOptions) -> Result<ReleaseOutcome> {
     // 1. Load config (inject CLI overrides for new_version & author flags in future)
-    let cfg = config::load_config_async(LoadOptions {
-        cwd: &opts.cwd,
-        cli_overrides: None,
-    })
-    .await?;
+    let cli_overrides = opts.no_metadata.then(RawConfig::without_metadata);
+    let mut cfg = match &opts.workspace_root {
+        Some(root) => config::load_member_config_async(root, &opts.cwd, cli_overrides).await?,
+        None => {
+            config::load_config_async(LoadOptions {
+                cwd: &opts.cwd,
+                cli_overrides,
+            })
+            .await?
+        }
+    };
+    config::log_warnings(&cfg);
+    // Changelog files are markdown; other formats can only be printed
+    if opts.block_format != BlockFormat::Markdown && !opts.dry_run {
+        return Err(NovalynError::Config(
+            "only markdown release blocks can be written to a changelog".into(),
+        )
+        .into());
+    }
+    if opts.no_compare {
+        cfg.compare_link = false;
+    }
+    if opts.no_emoji {
+        cfg.emoji = false;
+    }
+    if opts.no_manifest_version {
+        cfg.manifest_version_fallback = false;
+    }
+    let prerelease_format = opts.prerelease_format.clone().or_else(|| {
+        opts.prerelease
+            .as_ref()
+            .map(|channel| format!("{channel}.{{{{n}}}}").into())
+    });
+    if let Some(format) = &prerelease_format {
+        if !config::valid_prerelease_format(format) {
+            return Err(NovalynError::Config(format!(
+                "invalid prerelease identifier format '{format}'"
+            ))
+            .into());
+        }
+        cfg.prerelease_format = Some(format.clone());
+    }
+    if let Some(strategy) = opts.default_previous_tag_strategy
+        && !cfg.is_set("previous_tag_strategy")
+    {
+        cfg.previous_tag_strategy = strategy;
+    }
     debug!(types = cfg.types.len(), "config_loaded");
 
     // 2. Detect git repo & current ref
@@ -130,62 +279,195 @@ pub async fn run_release_async(opts: ReleaseOptions) -> Result<ReleaseOutcome> {
     let head = opts.to.clone().unwrap_or_else(|| "HEAD".into());
 
     // 3. Determine previous tag
-    let prev_tag = git::last_tag(&repo)?; // Option<String>
+    let prev_tag = match cfg.previous_tag_strategy {
+        PreviousTagStrategy::Latest => git::last_tag_with(&repo, |t| cfg.parse_tag(t))?,
+        PreviousTagStrategy::SemverReachable => {
+            git::max_reachable_semver_tag_with(&repo, &head, |t| cfg.parse_tag(t))?
+        }
+    };
 
-    // 4. Collect commits between prev_tag and head
+    // 4. Collect commits between the range start (default: prev_tag) and head
+    let range_from: Option<EcoString> = match (&opts.base, &opts.from) {
+        (Some(base), _) => Some(git::merge_base(&repo, base, &head)?.to_string().into()),
+        (None, Some(from)) => Some(from.clone()),
+        (None, None) => prev_tag.clone(),
+    };
     let raw = {
         let _span = tracing::span!(tracing::Level::DEBUG, "collect_commits").entered();
-        git::commits_between(&repo, prev_tag.as_deref(), &head)?
+        let mut filter = git::PathFilter {
+            include: opts.paths.clone(),
+            exclude: cfg.exclude_paths.clone(),
+        };
+        filter.exclude.extend(opts.exclude_paths.iter().cloned());
+        git::commits_between_filtered(&repo, range_from.as_deref(), &head, &filter, opts.limit)?
     };
     debug!(count = raw.len(), "commits_collected");
+    let raw_count = raw.len();
+    // Without a previous tag, the oldest commit in range is the compare base
+    let first_commit = if prev_tag.is_none() {
+        raw.first().map(|c| c.id.clone())
+    } else {
+        None
+    };
+
+    // 4b. Governance: refuse to release commits using a banned scope, checked
+    // on the raw headers so neither scope_map nor commit filters can hide one
+    let banned = parse::banned_scope_commits(&raw, &cfg);
+    if !banned.is_empty() {
+        let mut msg = format!("{} commit(s) use a banned scope:", banned.len());
+        for c in banned {
+            msg.push_str(&format!("\n  {} {}", c.short_id, c.summary));
+        }
+        return Err(NovalynError::Semantic(msg).into());
+    }
 
     // 5. Parse & classify
-    let parsed = {
+    let mut parsed = {
         let _span = tracing::span!(tracing::Level::DEBUG, "parse_classify").entered();
         parse::parse_and_classify(raw, &cfg)
     };
     debug!(count = parsed.len(), "commits_parsed");
 
-    // 6. Version inference: use 0.0.0 if no prev tag
+    // 5a. Strict mode: drop commits that only matched the fallback classification
+    let mut non_conventional = 0;
+    if opts.strict_conventional || cfg.strict_conventional {
+        for c in parsed.iter().filter(|c| !c.conventional) {
+            warn!(commit = %c.raw.short_id, summary = %c.raw.summary, "skipping non-conventional commit");
+            non_conventional += 1;
+        }
+        parsed.retain(|c| c.conventional);
+    }
+
+    // 5b. Interactive type assignment for commits without a conventional header
+    if opts.interactive {
+        if opts.yes || !std::io::stdin().is_terminal() {
+            debug!("not prompting for commit types (--yes or no terminal)");
+        } else {
+            let chosen = assign_types_interactively(&mut parsed, &cfg)?;
+            if opts.save_keyword_types && !chosen.is_empty() {
+                let path = config::save_keyword_types(&opts.cwd, &chosen)?;
+                info!(path = %path.display(), count = chosen.len(), "keyword_types saved");
+            }
+        }
+    }
+
+    // 5c. Annotate entries with pull request labels (network, opt-in)
+    if cfg.fetch_pr_labels {
+        let token = opts.github_token.as_ref().or(cfg.github_token.as_ref());
+        match (cfg.repo.as_ref(), token) {
+            (Some(repo), Some(token)) if repo.provider == Provider::GitHub => {
+                let prs = parsed.iter().filter_map(|c| c.pr);
+                let labels =
+                    github::fetch_pr_labels(repo, prs, token, Some(&cfg.github_api_url)).await;
+                for c in parsed.make_mut() {
+                    if let Some(found) = c.pr.and_then(|pr| labels.get(&pr)) {
+                        c.labels = found.clone();
+                    }
+                }
+            }
+            _ => debug!("fetch_pr_labels enabled but no GitHub repository or token; skipping"),
+        }
+    }
+
+    // 6. Version inference: without a previous tag, start from the manifest
+    // version (unless disabled), then 0.0.0
     let previous_version = prev_tag
         .as_ref()
-        .and_then(|t| semver::Version::parse(t.trim_start_matches('v')).ok())
+        .and_then(|t| cfg.parse_tag(t))
+        .or_else(|| {
+            cfg.manifest_version_fallback
+                .then(|| config::crate_version(&opts.cwd))
+                .flatten()
+        })
         .unwrap_or_else(|| semver::Version::new(0, 0, 0));
-    let (next_version, _bump) = {
+    // CLI --new-version wins over config `new_version`
+    let override_new = opts.new_version.clone().or_else(|| cfg.new_version.clone());
+    // Nothing left to release (no commits, or all of them filtered out)
+    let nothing_to_release = parsed.is_empty() && override_new.is_none();
+    if nothing_to_release && raw_count > 0 {
+        info!(
+            count = raw_count,
+            "all commits filtered out; nothing to release"
+        );
+    }
+    let (next_version, bump) = {
         let _span = tracing::span!(tracing::Level::DEBUG, "infer_version").entered();
-        parse::infer_version(&previous_version, &parsed, opts.new_version.clone())
+        let infer_opts = parse::InferOptions {
+            zero_major_feat: cfg.zero_major_feat,
+            min_bump: cfg.min_bump,
+            prerelease: cfg.prerelease_format_on(&crate::utils::today()),
+        };
+        parse::infer_version_with(&previous_version, &parsed, override_new, &infer_opts)
     };
     info!(version = %next_version, "version_inferred");
 
+    // 6b. Classification report for audits
+    if let Some(path) = &opts.report {
+        Report::new(&previous_version, &next_version, bump, &parsed)
+            .write(&opts.cwd.join(path))
+            .await?;
+    }
+
     // 7. Authors
     let authors = if opts.no_authors {
         None
     } else {
-        use ecow::{EcoString, EcoVec};
-
-        let aliases = scc::HashMap::with_hasher(foldhash::quality::RandomState::default());
-
-        let exclude: EcoVec<EcoString> = opts.exclude_authors.clone();
-
-        let mut authors = Authors::collect(
-            &parsed,
-            &AuthorOptions {
-                exclude,
-                hide_author_email: opts.hide_author_email,
-                no_authors: opts.no_authors,
-                aliases,
-                github_token: opts.github_token.as_ref().map(|s| s.to_string()),
-                enable_github_aliasing: opts.github_alias,
-            },
-        );
+        let mut author_opts = AuthorOptions {
+            exclude: opts.exclude_authors.clone(),
+            hide_author_email: opts.hide_author_email,
+            no_authors: opts.no_authors,
+            github_token: opts.github_token.as_ref().map(|s| s.to_string()),
+            enable_github_aliasing: opts.github_alias,
+            ..Default::default()
+        };
+        if let Some(path) = &cfg.authors_file {
+            AuthorsFile::load(&opts.cwd.join(path))
+                .await?
+                .apply(&mut author_opts);
+        }
+        // Anyone without commits in the history before the range is new
+        if cfg.new_contributors {
+            // Read one commit at a time rather than holding the earlier history
+            let prior = match &range_from {
+                Some(from) => Some(git::commits_iter(&repo, None, from)?),
+                None => None,
+            };
+            author_opts.set_prior_authors(prior.into_iter().flatten(), &cfg.co_author_keys);
+        }
+
+        let mut authors = Authors::collect(&parsed, &author_opts);
 
         // If GitHub aliasing is enabled and we have a token, resolve handles
+        // (skipped for non-GitHub providers)
         if opts.github_alias {
             if let Some(ref token) = opts.github_token {
+                let mut cache = match opts.handle_cache.then(HandleCache::default_path) {
+                    Some(Some(path)) => {
+                        Some(HandleCache::load(path, DEFAULT_HANDLE_CACHE_TTL).await)
+                    }
+                    Some(None) => {
+                        warn!("no cache directory found; GitHub handles will not be cached");
+                        None
+                    }
+                    None => None,
+                };
                 // Now we're already in async context, so we can just await
-                if let Err(e) = authors.resolve_github_handles(token).await {
+                if let Err(e) = authors
+                    .resolve_handles(
+                        cfg.repo.as_ref(),
+                        token,
+                        Some(&cfg.github_api_url),
+                        cache.as_mut(),
+                    )
+                    .await
+                {
                     warn!("failed to resolve GitHub handles: {}", e);
                 }
+                if let Some(cache) = &cache
+                    && let Err(e) = cache.save().await
+                {
+                    warn!("failed to save GitHub handle cache: {:#}", e);
+                }
             } else {
                 debug!(
                     "GitHub aliasing enabled but no token provided; skipping handle resolution (set GITHUB_TOKEN or GH_TOKEN env var, or use --no-github-alias to disable)"
@@ -205,39 +487,105 @@ pub async fn run_release_async(opts: ReleaseOptions) -> Result<ReleaseOutcome> {
         repo: cfg.repo.as_ref(),
         cfg: &cfg,
         previous_tag: prev_tag.as_deref(),
+        first_commit: first_commit.as_deref(),
         current_ref: &head,
     };
     let block = {
         let _span = tracing::span!(tracing::Level::DEBUG, "render").entered();
-        render_release_block(&rc)
+        render_release_block_as(&rc, opts.block_format.markup())
     };
 
+    // 8b. Release hooks (a dry run only lists them); a backfill releases nothing new
+    let (pre_hooks, post_hooks) =
+        if opts.run_hooks && !nothing_to_release && opts.insert_after.is_none() {
+            (
+                interpolate_hooks(&cfg.pre_release_hooks, &previous_version, &next_version),
+                interpolate_hooks(&cfg.post_release_hooks, &previous_version, &next_version),
+            )
+        } else {
+            (EcoVec::new(), EcoVec::new())
+        };
+    let mut skipped_hooks = EcoVec::new();
+    if opts.dry_run {
+        skipped_hooks.extend(pre_hooks.iter().chain(&post_hooks).cloned());
+    }
+
     // 9. Update changelog & tag
-    let changed = if opts.dry_run {
+    let changelog_file = opts
+        .output
+        .as_deref()
+        .unwrap_or(std::path::Path::new("CHANGELOG.md"));
+    let changelog_path = opts.cwd.join(changelog_file);
+    let changed = if opts.dry_run || nothing_to_release {
         false
     } else {
         // Confirm changelog update unless --yes was specified
-        let should_write = confirm_action("Update CHANGELOG.md?", opts.yes)?;
+        let prompt = format!("Update {}?", changelog_file.display());
+        let should_write = confirm_action(&prompt, opts.yes)?;
 
         if should_write {
+            // Pre-release hooks: a failing hook aborts before anything is written
+            for hook in &pre_hooks {
+                let status = run_hook(&opts.cwd, hook)
+                    .await
+                    .map_err(|e| NovalynError::Io(format!("pre-release hook `{hook}`: {e}")))?;
+                if !status.success() {
+                    return Err(NovalynError::Semantic(format!(
+                        "pre-release hook `{hook}` failed ({status}); release aborted"
+                    ))
+                    .into());
+                }
+            }
             let _span = tracing::span!(tracing::Level::DEBUG, "write_changelog").entered();
-            changelog::write_or_update_changelog_async(&opts.cwd, &block).await?
+            let write_opts = changelog::WriteOptions {
+                intro: cfg.intro.clone(),
+                force: opts.force,
+                insert_after: opts.insert_after.clone(),
+            };
+            changelog::write_changelog_file_async(&changelog_path, &block, &write_opts).await?
         } else {
             false
         }
     };
-    if changed && !opts.dry_run {
+    // A backfilled release is older than the manifest version
+    if changed && opts.update_lockfile && opts.insert_after.is_none() {
+        let mut locked = false;
+        for name in parse::bump_cargo_version(&opts.cwd, &next_version)? {
+            locked |= parse::bump_cargo_lock(&opts.cwd, &name, &next_version)?;
+        }
+        if !locked {
+            warn!("no Cargo.lock entry found for the package; lockfile left unchanged");
+        }
+    }
+    // A backfilled release is not at HEAD, so it is never tagged
+    if changed && !opts.dry_run && !opts.no_tag && opts.insert_after.is_none() {
         // Confirm tag creation unless --yes was specified
-        let should_tag = confirm_action(&format!("Create git tag v{}?", next_version), opts.yes)?;
+        let should_tag = confirm_action(
+            &format!("Create git tag {}?", cfg.tag_name(&next_version)),
+            opts.yes,
+        )?;
 
         if should_tag {
-            // create tag (annotated optionally sign placeholder)
-            let tag_name = format!("v{}", next_version);
-            let tag_msg = format!("v{}", next_version);
-            let _ = {
-                let _span = tracing::span!(tracing::Level::DEBUG, "tag").entered();
-                git::create_tag(&mut repo, &tag_name, &tag_msg, true)
-            };
+            // create annotated tag, GPG-signed with --sign
+            let tag_name = cfg.tag_name(&next_version);
+            let tag_msg = tag_name.clone();
+            let _span = tracing::span!(tracing::Level::DEBUG, "tag").entered();
+            if opts.sign {
+                git::create_signed_tag(&mut repo, &tag_name, &tag_msg)?;
+            } else {
+                let _ = git::create_tag(&mut repo, &tag_name, &tag_msg, true);
+            }
+        }
+    }
+
+    // 10. Post-release hooks: the release stands even if one fails
+    if changed {
+        for hook in &post_hooks {
+            match run_hook(&opts.cwd, hook).await {
+                Ok(status) if status.success() => {}
+                Ok(status) => warn!(hook = %hook, %status, "post-release hook failed"),
+                Err(e) => warn!(hook = %hook, "post-release hook could not be run: {e}"),
+            }
         }
     }
 
@@ -249,13 +597,153 @@ pub async fn run_release_async(opts: ReleaseOptions) -> Result<ReleaseOutcome> {
     Ok(ReleaseOutcome {
         version: next_version.clone(),
         previous: Some(previous_version.clone()),
+        bump,
         wrote: changed,
-        changelog_path: opts.cwd.join("CHANGELOG.md"),
+        changelog_path,
         commit_count: rc.commits.len(),
+        non_conventional,
+        block,
         exit,
+        warnings: cfg.warnings.clone(),
+        skipped_hooks,
     })
 }
 
+/// Release hook commands with `{{newVersion}}`, `{{previousVersion}}` and
+/// `{{date}}` filled in.
+fn interpolate_hooks(
+    hooks: &[EcoString],
+    previous: &semver::Version,
+    next: &semver::Version,
+) -> EcoVec<EcoString> {
+    let date = crate::utils::today().parse().unwrap_or_default();
+    hooks
+        .iter()
+        .map(|hook| parse::interpolate(hook, previous, next, &date))
+        .collect()
+}
+
+/// Run a release hook through the platform shell, in `cwd`.
+async fn run_hook(cwd: &std::path::Path, hook: &str) -> std::io::Result<std::process::ExitStatus> {
+    info!(hook, "running release hook");
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.args(["/C", hook]);
+        cmd
+    } else {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.args(["-c", hook]);
+        cmd
+    };
+    cmd.current_dir(cwd).status().await
+}
+
+/// Run the release pipeline for every member of a Cargo workspace concurrently.
+///
+/// Members are read from the `[workspace]` table of the root Cargo.toml in
+/// `opts.cwd`. Each member only sees commits touching its directory, writes its
+/// own CHANGELOG.md, and layers its config over the workspace root config.
+/// No tags are created since members share the repository's tag namespace.
+///
+/// Members run on blocking threads at the same time, so they cannot prompt:
+/// writing requires `opts.yes` and `opts.interactive` is rejected. With
+/// `opts.update_lockfile`, manifests and Cargo.lock are bumped once after
+/// every member finished (see [`bump_member_manifests`]).
+///
+/// # Returns
+/// * `Ok(outcomes)` - Member directory and release outcome, in member order
+/// * `Err` - Workspace discovery or any member release failed
+#[instrument(skip_all, fields(cwd = %opts.cwd.display()))]
+pub async fn run_workspace_release_async(
+    opts: ReleaseOptions,
+) -> Result<Vec<(std::path::PathBuf, ReleaseOutcome)>> {
+    if opts.interactive || (!opts.dry_run && !opts.yes) {
+        return Err(NovalynError::Config(
+            "workspace members are released concurrently and cannot prompt; pass --yes".into(),
+        )
+        .into());
+    }
+    let repo = git::detect_repo(&opts.cwd)?;
+    let repo_root = repo
+        .workdir()
+        .ok_or_else(|| anyhow::anyhow!("repository has no working directory"))?
+        .canonicalize()?;
+    let members = workspace::members(&opts.cwd)?;
+    debug!(count = members.len(), "workspace_members");
+
+    let handle = tokio::runtime::Handle::current();
+    let mut runs = tokio::task::JoinSet::new();
+    for (index, member) in members.into_iter().enumerate() {
+        let member_rel = member
+            .canonicalize()?
+            .strip_prefix(&repo_root)?
+            .to_path_buf();
+        let mut member_opts = opts.clone();
+        member_opts.cwd = member.clone();
+        member_opts.paths.push(member_rel);
+        member_opts.workspace_root = Some(opts.cwd.clone());
+        member_opts.no_tag = true;
+        // Shared manifests and Cargo.lock are bumped once all members are done
+        member_opts.update_lockfile = false;
+        let handle = handle.clone();
+        runs.spawn_blocking(move || {
+            let outcome = handle.block_on(run_release_async(member_opts))?;
+            Ok::<_, anyhow::Error>((index, member, outcome))
+        });
+    }
+    let mut outcomes = Vec::with_capacity(runs.len());
+    while let Some(run) = runs.join_next().await {
+        outcomes.push(run??);
+    }
+    outcomes.sort_by_key(|(index, ..)| *index);
+    let outcomes: Vec<_> = outcomes
+        .into_iter()
+        .map(|(_, member, outcome)| (member, outcome))
+        .collect();
+
+    if opts.update_lockfile && opts.insert_after.is_none() {
+        bump_member_manifests(&opts.cwd, &outcomes)?;
+    }
+    Ok(outcomes)
+}
+
+/// Bump the manifests and Cargo.lock entries of the workspace members whose
+/// changelog was written.
+///
+/// Members inheriting `[workspace.package] version` share a single version, so
+/// the workspace is bumped once, to the highest version inferred among them.
+fn bump_member_manifests(
+    root: &std::path::Path,
+    outcomes: &[(std::path::PathBuf, ReleaseOutcome)],
+) -> Result<()> {
+    let mut bumped: Vec<(EcoString, semver::Version)> = Vec::new();
+    let mut inherited: Vec<(&std::path::Path, &semver::Version)> = Vec::new();
+    for (member, outcome) in outcomes.iter().filter(|(_, o)| o.wrote) {
+        if parse::inherits_workspace_version(member)? {
+            inherited.push((member, &outcome.version));
+        } else {
+            let names = parse::bump_cargo_version(member, &outcome.version)?;
+            bumped.extend(names.into_iter().map(|n| (n, outcome.version.clone())));
+        }
+    }
+    if let Some(&(member, version)) = inherited.iter().max_by_key(|(_, v)| *v) {
+        if inherited.iter().any(|(_, v)| *v != version) {
+            warn!(
+                version = %version,
+                "members inheriting the workspace version inferred different versions; using the highest"
+            );
+        }
+        let names = parse::bump_cargo_version(member, version)?;
+        bumped.extend(names.into_iter().map(|n| (n, version.clone())));
+    }
+    for (name, version) in &bumped {
+        if !parse::bump_cargo_lock(root, name, version)? {
+            warn!(package = %name, "no Cargo.lock entry found; lockfile left unchanged");
+        }
+    }
+    Ok(())
+}
+
 /// Execute the complete release pipeline synchronously (for backward compatibility).
 ///
 /// This is a wrapper around `run_release_async` that blocks on the async runtime.
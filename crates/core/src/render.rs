@@ -1,10 +1,13 @@
 use crate::{
-    authors::Authors,
-    config::ResolvedConfig,
-    parse::ParsedCommit,
-    repository::{Repository, format_compare_changes},
+    authors::{Author, Authors},
+    config::{DEFAULT_CONTRIBUTOR_GROUP, ResolvedConfig, SectionSort},
+    conventional::is_breaking_footer,
+    parse::{ParsedCommit, interpolate},
+    repository::{Repository, short_sha},
+    utils,
 };
 use ecow::EcoString;
+use std::borrow::Cow;
 
 /// Context for rendering a changelog release block.
 ///
@@ -18,18 +21,150 @@ pub struct RenderContext<'a> {
     pub repo: Option<&'a Repository>,
     pub cfg: &'a ResolvedConfig,
     pub previous_tag: Option<&'a str>,
+    /// Full SHA of the oldest commit in range, used as compare base without a previous tag
+    pub first_commit: Option<&'a str>,
     /// Current git reference (branch or tag name)
     pub current_ref: &'a str,
 }
 
+/// Markup syntax of a rendered release block.
+///
+/// [`render_release_block_as`] lays out the block; implementations only
+/// decide how headings, links, lists and emphasis are spelled.
+pub trait Markup: Sync {
+    /// Heading line(s) for `text`; level 2 is the release, 3 a section, 4 a subgroup.
+    fn heading(&self, level: usize, text: &str) -> String;
+    /// Inline link.
+    fn link(&self, text: &str, url: &str) -> String;
+    /// Emphasized inline text.
+    fn emphasis(&self, text: &str) -> String;
+    /// A plain list item line.
+    fn item(&self, text: &str) -> String;
+    /// List nested under the preceding entry.
+    fn sub_items(&self, items: &[String]) -> String;
+    /// Quoted lines nested under the preceding entry.
+    fn quote(&self, text: &str) -> String;
+    /// Whether long sections can fold into a `<details>` block.
+    fn collapsible(&self) -> bool;
+}
+
+/// GitHub-flavoured Markdown, the changelog format.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Markdown;
+
+impl Markup for Markdown {
+    fn heading(&self, level: usize, text: &str) -> String {
+        format!("{} {text}\n", "#".repeat(level))
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        format!("[{text}]({url})")
+    }
+
+    fn emphasis(&self, text: &str) -> String {
+        format!("_{text}_")
+    }
+
+    fn item(&self, text: &str) -> String {
+        format!("- {text}\n")
+    }
+
+    fn sub_items(&self, items: &[String]) -> String {
+        items.iter().map(|item| format!("  - {item}\n")).collect()
+    }
+
+    fn quote(&self, text: &str) -> String {
+        text.lines()
+            .map(|l| format!("{}\n", format!("  > {l}").trim_end()))
+            .collect()
+    }
+
+    fn collapsible(&self) -> bool {
+        true
+    }
+}
+
+/// reStructuredText, for Sphinx-based docs.
+///
+/// Section titles are underlined (`=`, `-`, `~` by level) and links are
+/// anonymous, so repeated link texts don't clash.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rst;
+
+impl Markup for Rst {
+    fn heading(&self, level: usize, text: &str) -> String {
+        let underline = match level {
+            0..=2 => '=',
+            3 => '-',
+            _ => '~',
+        };
+        // Wide characters such as emoji take two columns
+        let width: usize = text.chars().map(|c| if c.is_ascii() { 1 } else { 2 }).sum();
+        format!("{text}\n{}\n\n", underline.to_string().repeat(width))
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        format!("`{text} <{url}>`__")
+    }
+
+    fn emphasis(&self, text: &str) -> String {
+        format!("*{text}*")
+    }
+
+    fn item(&self, text: &str) -> String {
+        format!("* {text}\n")
+    }
+
+    fn sub_items(&self, items: &[String]) -> String {
+        if items.is_empty() {
+            return String::new();
+        }
+        // Nested lists need blank lines around them
+        let list: String = items.iter().map(|item| format!("  * {item}\n")).collect();
+        format!("\n{list}\n")
+    }
+
+    fn quote(&self, text: &str) -> String {
+        let lines: String = text
+            .lines()
+            .map(|l| format!("{}\n", format!("    {l}").trim_end()))
+            .collect();
+        format!("\n{lines}\n")
+    }
+
+    fn collapsible(&self) -> bool {
+        false
+    }
+}
+
+/// Markup a release block can be rendered in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlockFormat {
+    #[default]
+    Markdown,
+    Rst,
+}
+
+impl BlockFormat {
+    pub fn markup(self) -> &'static dyn Markup {
+        match self {
+            Self::Markdown => &Markdown,
+            Self::Rst => &Rst,
+        }
+    }
+}
+
 /// Render a changelog release block in markdown format with parallel section rendering.
 ///
 /// Generates a formatted release section with:
 /// - Version header with compare link
+/// - Breaking commits whose type has no visible section (all of them with
+///   `group_breaking`)
 /// - Commits grouped by type (features, fixes, etc.)
 /// - Breaking change indicators
 /// - Issue references with links
-/// - Contributors section
+/// - Contributors section, with first-time contributors when enabled
+/// - Configured outro
 ///
 /// # Arguments
 /// * `ctx` - Render context with commits, version, and configuration
@@ -37,31 +172,111 @@ pub struct RenderContext<'a> {
 /// # Returns
 /// Formatted markdown release block as a string
 pub fn render_release_block(ctx: &RenderContext<'_>) -> EcoString {
+    render_release_block_as(ctx, &Markdown)
+}
+
+/// Render a release block like [`render_release_block`], in `markup`.
+pub fn render_release_block_as(ctx: &RenderContext<'_>, markup: &dyn Markup) -> EcoString {
     use rayon::prelude::*;
 
+    let unreverted;
+    let filtered;
+    let ctx = if ctx.cfg.hide_reverted {
+        unreverted = drop_reverted(ctx.commits);
+        filtered = RenderContext {
+            commits: &unreverted,
+            ..*ctx
+        };
+        &filtered
+    } else {
+        ctx
+    };
+
     let mut out = String::new();
     // Header
-    out.push_str(&format!("## v{}", ctx.version));
-    out.push('\n');
-    if let (Some(_prev), Some(repo), Some(prev_tag)) =
-        (ctx.previous_version, ctx.repo, ctx.previous_tag)
-        && let Some(compare) =
-            format_compare_changes(None, prev_tag, &format!("v{}", ctx.version), Some(repo))
+    out.push_str(&markup.heading(2, &format!("v{}", ctx.version)));
+    if ctx.cfg.compare_link
+        && let (Some(_prev), Some(repo), Some(base)) = (
+            ctx.previous_version,
+            ctx.repo,
+            ctx.previous_tag.or(ctx.first_commit),
+        )
     {
-        out.push_str(&compare);
+        let url = repo.compare_url(
+            compare_endpoint(base, ctx.cfg.compare_short_sha),
+            &ctx.cfg.tag_name(ctx.version),
+        );
+        if !url.is_empty() {
+            out.push_str(&markup.link("compare changes", &url));
+            out.push('\n');
+        }
+    }
+    if ctx.cfg.show_range {
+        let from = match (ctx.previous_tag, ctx.first_commit) {
+            (Some(tag), _) => Some(EcoString::from(tag)),
+            (None, Some(sha)) => Some(short_sha(sha).into()),
+            (None, None) => None,
+        };
+        let count = ctx.commits.len();
+        let noun = if count == 1 { "commit" } else { "commits" };
+        let to = ctx.cfg.tag_name(ctx.version);
+        let range = match from {
+            Some(from) => format!("Changes from {from} to {to} ({count} {noun})"),
+            None => format!("Changes up to {to} ({count} {noun})"),
+        };
+        out.push_str(&markup.emphasis(&range));
         out.push('\n');
     }
 
+    // Breaking commits no enabled type section would show, or all of them
+    // when grouped
+    let grouped = ctx.cfg.group_breaking && !ctx.cfg.breaking_section_title.is_empty();
+    if !ctx.cfg.breaking_section_title.is_empty() {
+        let mut listed: Vec<&ParsedCommit> = ctx
+            .commits
+            .iter()
+            .filter(|c| {
+                c.breaking
+                    && (grouped
+                        || !ctx
+                            .cfg
+                            .types
+                            .iter()
+                            .any(|tc| tc.enabled && tc.key == c.r#type))
+            })
+            .collect();
+        if !listed.is_empty() {
+            listed.sort_by_key(|c| c.index);
+            out.push('\n');
+            out.push_str(&markup.heading(3, &ctx.cfg.breaking_section_title));
+            for c in listed {
+                push_entry(&mut out, ctx, markup, c, &c.r#type, false);
+                if grouped {
+                    for (_, note) in c.footers.iter().filter(|(k, _)| is_breaking_footer(k)) {
+                        for l in note.lines() {
+                            out.push_str(format!("  {l}").trim_end());
+                            out.push('\n');
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let skip_breaking = grouped && ctx.cfg.dedupe_breaking;
+
     // Render sections in parallel for better performance
-    let sections: Vec<(usize, String)> = ctx
+    let mut sections: Vec<(usize, String)> = ctx
         .cfg
         .types
         .par_iter()
         .enumerate()
         .filter(|(_, tc)| tc.enabled)
         .filter_map(|(idx, tc)| {
-            let mut candidates: Vec<&ParsedCommit> =
-                ctx.commits.iter().filter(|c| c.r#type == tc.key).collect();
+            let mut candidates: Vec<&ParsedCommit> = ctx
+                .commits
+                .iter()
+                .filter(|c| c.r#type == tc.key && !(skip_breaking && c.breaking))
+                .collect();
 
             if candidates.is_empty() {
                 return None;
@@ -72,39 +287,55 @@ pub fn render_release_block(ctx: &RenderContext<'_>) -> EcoString {
 
             let mut section = String::new();
             section.push('\n');
-            section.push_str(&format!("### {} {}", tc.emoji, tc.title));
-            section.push('\n');
+            let title = match &tc.singular_title {
+                Some(singular) if candidates.len() == 1 => singular,
+                _ => &tc.title,
+            };
+            let emoji = if ctx.cfg.emoji { tc.emoji.as_str() } else { "" };
+            let heading = if emoji.is_empty() {
+                markup.heading(3, title)
+            } else {
+                markup.heading(3, &format!("{emoji} {title}"))
+            };
+            let collapsed = markup.collapsible()
+                && ctx
+                    .cfg
+                    .collapse_threshold
+                    .is_some_and(|max| candidates.len() > max);
+            if collapsed {
+                // The blank line after the summary keeps the entries rendering as markdown
+                section.push_str(&format!(
+                    "<details><summary>{} ({})</summary>\n\n",
+                    heading.trim_end(),
+                    candidates.len()
+                ));
+            } else {
+                section.push_str(&heading);
+            }
 
+            // Types without an emoji can name themselves instead
+            let label = if emoji.is_empty() && ctx.cfg.show_type_label {
+                &tc.key
+            } else {
+                emoji
+            };
             for c in candidates {
-                let mut line = String::new();
-                if let Some(scope) = &c.scope {
-                    line.push_str(&format!("* {}({}): {}", tc.emoji, scope, c.description));
-                } else {
-                    line.push_str(&format!("* {}: {}", tc.emoji, c.description));
-                }
-                if c.breaking {
-                    line.push_str(" (BREAKING)");
-                }
-                if !c.issues.is_empty() {
-                    let refs: Vec<String> = if let Some(repo) = ctx.repo {
-                        c.issues
-                            .iter()
-                            .map(|n| format!("[#{}]({})", n, repo.issue_url(*n)))
-                            .collect()
-                    } else {
-                        c.issues.iter().map(|n| format!("#{}", n)).collect()
-                    };
-                    line.push_str(&format!(" ({})", refs.join(", ")));
-                }
-                section.push_str(&line);
-                section.push('\n');
+                push_entry(&mut section, ctx, markup, c, label, true);
+            }
+            if collapsed {
+                section.push_str("\n</details>\n");
             }
 
             Some((idx, section))
         })
         .collect();
 
-    // Append sections in original order to maintain deterministic output
+    if ctx.cfg.section_sort == SectionSort::Impact {
+        // Stable sort keeps config order within the same impact
+        sections.sort_by_key(|(idx, _)| ctx.cfg.types[*idx].semver.rank());
+    }
+
+    // Append sections in a deterministic order
     for (_, section) in sections {
         out.push_str(&section);
     }
@@ -115,26 +346,309 @@ pub fn render_release_block(ctx: &RenderContext<'_>) -> EcoString {
         && !auths.list.is_empty()
     {
         out.push('\n');
-        out.push_str("### Contributors\n");
-        for a in &auths.list {
-            if let Some(email) = &a.email {
-                out.push_str(&format!("- {} <{}>\n", a.name, email));
-            } else {
-                out.push_str(&format!("- {}\n", a.name));
+        out.push_str(&markup.heading(3, "Contributors"));
+        if ctx.cfg.contributor_groups.is_empty() {
+            push_contributors(&mut out, markup, &auths.list);
+        } else {
+            // Configured groups by label, then everyone else
+            let mut groups: std::collections::BTreeMap<&str, Vec<&Author>> = Default::default();
+            let mut ungrouped = Vec::new();
+            for a in &auths.list {
+                match a
+                    .domain
+                    .as_deref()
+                    .and_then(|domain| ctx.cfg.contributor_groups.get(domain))
+                {
+                    Some(label) => groups.entry(label).or_default().push(a),
+                    None => ungrouped.push(a),
+                }
+            }
+            let ungrouped =
+                (!ungrouped.is_empty()).then_some((DEFAULT_CONTRIBUTOR_GROUP, ungrouped));
+            for (label, members) in groups.into_iter().chain(ungrouped) {
+                out.push('\n');
+                out.push_str(&markup.heading(4, label));
+                push_contributors(&mut out, markup, members);
+            }
+        }
+        if ctx.cfg.new_contributors && auths.list.iter().any(|a| a.first_time) {
+            out.push('\n');
+            out.push_str(&markup.heading(3, "New Contributors"));
+            for a in auths.list.iter().filter(|a| a.first_time) {
+                out.push_str(&markup.item(&format!("{} made their first contribution", a.name)));
             }
         }
     }
+    if let Some(outro) = &ctx.cfg.outro {
+        let previous = ctx
+            .previous_version
+            .cloned()
+            .unwrap_or_else(|| semver::Version::new(0, 0, 0));
+        let date = utils::today().parse().unwrap_or_default();
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push('\n');
+        out.push_str(&interpolate(outro, &previous, ctx.version, &date));
+    }
     if !out.ends_with('\n') {
         out.push('\n');
     }
     out.into()
 }
 
+/// Append a `- Name <email>` item per author to `out`.
+fn push_contributors<'a>(
+    out: &mut String,
+    markup: &dyn Markup,
+    authors: impl IntoIterator<Item = &'a Author>,
+) {
+    for a in authors {
+        if let Some(email) = &a.email {
+            out.push_str(&markup.item(&format!("{} <{}>", a.name, email)));
+        } else {
+            out.push_str(&markup.item(&a.name));
+        }
+    }
+}
+
+/// `commits` without the ones reverted by another commit of the same
+/// release, and without those reverts.
+///
+/// Reverts of commits from earlier releases are kept.
+fn drop_reverted(commits: &[ParsedCommit]) -> Vec<ParsedCommit> {
+    let targets = |revert: &ParsedCommit| {
+        let sha = revert.reverts.as_deref()?;
+        commits
+            .iter()
+            .find(|c| c.raw.id.starts_with(sha) || c.raw.short_id == sha)
+    };
+    let mut dropped = std::collections::HashSet::new();
+    for revert in commits {
+        if let Some(target) = targets(revert) {
+            dropped.insert(revert.index);
+            dropped.insert(target.index);
+        }
+    }
+    commits
+        .iter()
+        .filter(|c| !dropped.contains(&c.index))
+        .cloned()
+        .collect()
+}
+
+/// Append the bullet line for `c` (and its migration notes) to `out`.
+///
+/// `label` precedes the scope; `tag_breaking` appends the `(BREAKING)` marker.
+fn push_entry(
+    out: &mut String,
+    ctx: &RenderContext<'_>,
+    markup: &dyn Markup,
+    c: &ParsedCommit,
+    label: &str,
+    tag_breaking: bool,
+) {
+    let mut line = match &ctx.cfg.commit_template {
+        Some(template) => expand_commit_template(template, ctx, markup, c, label, tag_breaking),
+        None => {
+            let mut line = String::new();
+            let description = entry_description(c, ctx.cfg);
+            let marker = match &c.scope {
+                Some(scope) => format!("{}({}):", label, scope),
+                None if label.is_empty() => String::new(),
+                None => format!("{}:", label),
+            };
+            line.push_str(&join_nonempty("*", &marker, &description));
+            if c.breaking && tag_breaking {
+                line.push_str(" (BREAKING)");
+            }
+            if let Some(refs) = issue_refs(ctx, markup, c) {
+                line.push_str(&format!(" ({refs})"));
+            }
+            if ctx.cfg.link_commits {
+                line.push_str(&format!(" ({})", commit_link(ctx, markup, c)));
+            }
+            line
+        }
+    };
+    for label in &c.labels {
+        line.push_str(&format!(" [{}]", label));
+    }
+    for key in &ctx.cfg.render_footers {
+        let values: Vec<&str> = c
+            .footers
+            .iter()
+            .filter(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+            .collect();
+        if !values.is_empty() {
+            let phrase = key.to_lowercase().replace("-", " ");
+            line.push_str(&format!(" — {} {}", phrase, values.join(", ")));
+        }
+    }
+    out.push_str(&line);
+    out.push('\n');
+    if ctx.cfg.expand_body_bullets {
+        out.push_str(&markup.sub_items(&body_bullets(&c.body)));
+    }
+    if let Some(migration) = &c.migration {
+        out.push_str(&markup.quote(migration));
+    }
+}
+
+/// Linked issue and merge-request references of an entry, e.g. `[#1](...), +2 more`.
+fn issue_refs(ctx: &RenderContext<'_>, markup: &dyn Markup, c: &ParsedCommit) -> Option<String> {
+    let total = c.issues.len() + c.merge_requests.len();
+    if total == 0 {
+        return None;
+    }
+    let shown = ctx
+        .cfg
+        .max_refs_per_entry
+        .map_or(total, |max| max.min(total));
+    // Types with `link_refs = false` keep their references as plain text
+    let repo = ctx.repo.filter(|_| {
+        ctx.cfg
+            .types
+            .iter()
+            .find(|tc| tc.key == c.r#type)
+            .is_none_or(|tc| tc.link_refs)
+    });
+    let issues = c.issues.iter().map(|n| {
+        let reference = match repo {
+            Some(repo) => markup.link(&format!("#{n}"), &repo.issue_url(*n)),
+            None => format!("#{}", n),
+        };
+        if ctx.cfg.show_closed_issues && c.closed_issues.contains(n) {
+            format!("closes {reference}")
+        } else {
+            reference
+        }
+    });
+    let merge_requests = c.merge_requests.iter().map(|n| match repo {
+        Some(repo) => markup.link(&format!("!{n}"), &repo.pr_url(*n)),
+        None => format!("!{n}"),
+    });
+    let mut refs: Vec<String> = issues.chain(merge_requests).take(shown).collect();
+    let hidden = total - shown;
+    if hidden > 0 {
+        refs.push(format!("+{hidden} more"));
+    }
+    Some(refs.join(", "))
+}
+
+/// Short id of an entry's commit, linked when the repository is known.
+fn commit_link(ctx: &RenderContext<'_>, markup: &dyn Markup, c: &ParsedCommit) -> String {
+    match ctx.repo {
+        Some(repo) => markup.link(&c.raw.short_id, &repo.commit_url(&c.raw.id)),
+        None => c.raw.short_id.to_string(),
+    }
+}
+
+/// Render an entry line from the `commit_template` config.
+///
+/// Unknown tokens and tokens without a value render empty; the spaces they
+/// leave behind are collapsed.
+fn expand_commit_template(
+    template: &str,
+    ctx: &RenderContext<'_>,
+    markup: &dyn Markup,
+    c: &ParsedCommit,
+    label: &str,
+    tag_breaking: bool,
+) -> String {
+    let mut out = String::with_capacity(template.len() + c.description.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after
+            .find('}')
+            .filter(|&end| after[..end].chars().all(|ch| ch.is_ascii_alphabetic()))
+        else {
+            out.push('{');
+            rest = after;
+            continue;
+        };
+        match &after[..end] {
+            "emoji" => out.push_str(label),
+            "scope" => out.push_str(c.scope.as_deref().unwrap_or_default()),
+            "description" => out.push_str(&entry_description(c, ctx.cfg)),
+            "hash" => out.push_str(&c.raw.id),
+            "shortHash" => out.push_str(&c.raw.short_id),
+            "hashUrl" => out.push_str(&commit_link(ctx, markup, c)),
+            "issues" => out.push_str(&issue_refs(ctx, markup, c).unwrap_or_default()),
+            "breaking" if c.breaking && tag_breaking => out.push_str("(BREAKING)"),
+            _ => {}
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out.split(' ')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Top-level `- ` / `* ` bullets of a commit body.
+///
+/// Indented continuation lines are folded into the preceding bullet; nested
+/// bullets and paragraphs are left out.
+fn body_bullets(body: &str) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    let mut open = false;
+    for line in body.lines() {
+        if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            items.push(item.trim().to_string());
+            open = true;
+        } else if open && line.starts_with([' ', '\t']) && !line.trim().is_empty() {
+            let text = line.trim();
+            if text.starts_with("- ") || text.starts_with("* ") {
+                continue;
+            }
+            if let Some(last) = items.last_mut() {
+                last.push(' ');
+                last.push_str(text);
+            }
+        } else {
+            open = false;
+        }
+    }
+    items
+}
+
+/// Description shown for an entry.
+///
+/// `revert` entries show the configured prefix followed by the reverted
+/// subject, with the quotes of git's `Revert "..."` summaries removed.
+fn entry_description<'c>(c: &'c ParsedCommit, cfg: &ResolvedConfig) -> Cow<'c, str> {
+    if c.r#type == "revert" && !cfg.revert_prefix.is_empty() {
+        let subject = c.description.trim().trim_matches('"');
+        Cow::Owned(format!("{}{}", cfg.revert_prefix, subject))
+    } else {
+        Cow::Borrowed(&c.description)
+    }
+}
+
+/// Join `lead`, `mid` and `tail` with single spaces, leaving out an empty `mid`.
+fn join_nonempty(lead: &str, mid: &str, tail: &str) -> String {
+    if mid.is_empty() {
+        format!("{lead} {tail}")
+    } else {
+        format!("{lead} {mid} {tail}")
+    }
+}
+
+/// Shorten a full commit SHA used as a compare endpoint when configured to.
+fn compare_endpoint(rev: &str, short: bool) -> &str {
+    if short { short_sha(rev) } else { rev }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        config::{ResolvedConfig, default_types},
+        config::{ResolvedConfig, SemverImpact, default_types},
         git::RawCommit,
         parse::ParsedCommit,
     };
@@ -149,9 +663,33 @@ mod tests {
             cwd: std::path::PathBuf::from("."),
             source_file: None,
             repo: None,
+            ..Default::default()
+        }
+    }
+
+    /// Version of the blocks rendered through [`ctx`].
+    static VERSION: semver::Version = semver::Version::new(1, 0, 0);
+
+    /// Context rendering `commits` as v1.0.0 with no repository, authors or
+    /// range; tests override the fields they care about.
+    fn ctx<'a>(cfg: &'a ResolvedConfig, commits: &'a [ParsedCommit]) -> RenderContext<'a> {
+        RenderContext {
+            version: &VERSION,
+            previous_version: None,
+            commits,
+            authors: None,
+            repo: None,
+            cfg,
+            previous_tag: None,
+            first_commit: None,
+            current_ref: "HEAD",
         }
     }
 
+    fn render(cfg: &ResolvedConfig, commits: &[ParsedCommit]) -> EcoString {
+        render_release_block(&ctx(cfg, commits))
+    }
+
     fn mk_commit(t: &str, desc: &str) -> ParsedCommit {
         ParsedCommit {
             raw: RawCommit {
@@ -173,6 +711,7 @@ mod tests {
             co_authors: vec![].into(),
             type_cfg: None,
             index: 0,
+            ..Default::default()
         }
     }
 
@@ -180,19 +719,566 @@ mod tests {
     fn basic_render() {
         let cfg = dummy_cfg();
         let commits = vec![mk_commit("feat", "add"), mk_commit("fix", "bug")];
-        let rc = RenderContext {
-            version: &semver::Version::parse("1.0.0").unwrap(),
-            previous_version: None,
-            commits: &commits,
-            authors: None,
-            repo: None,
-            cfg: &cfg,
-            previous_tag: None,
-            current_ref: "HEAD",
-        };
-        let txt = render_release_block(&rc);
+        let txt = render(&cfg, &commits);
         assert!(txt.contains("## v1.0.0"));
         assert!(txt.contains("### ✨ Features"));
         assert!(txt.contains("### 🐞 Bug Fixes"));
     }
+
+    #[test]
+    fn singular_title_for_single_entry_sections() {
+        let mut cfg = dummy_cfg();
+        for tc in &mut cfg.types {
+            tc.singular_title = Some(tc.title.trim_end_matches('s').into());
+        }
+        let commits = vec![
+            mk_commit("feat", "add"),
+            mk_commit("fix", "one"),
+            mk_commit("fix", "two"),
+        ];
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains("\n### ✨ Feature\n"), "{txt}");
+        assert!(txt.contains("\n### 🐞 Bug Fixes\n"), "{txt}");
+    }
+
+    #[test]
+    fn commit_template_tokens() {
+        let mut cfg = dummy_cfg();
+        cfg.commit_template = Some("* {description} ({hashUrl}) {issues} {unknown}".into());
+        let mut c = mk_commit("fix", "bug");
+        c.raw.id = "abcdef0123".into();
+        c.raw.short_id = "abcdef0".into();
+        c.issues = vec![7].into();
+        let commits = vec![c];
+        let repo = Repository::parse("https://github.com/o/r.git").unwrap();
+        let render = |repo| {
+            render_release_block(&RenderContext {
+                repo,
+                ..ctx(&cfg, &commits)
+            })
+        };
+        let linked = render(Some(&repo));
+        assert!(
+            linked.contains(
+                "\n* bug ([abcdef0](https://github.com/o/r/commit/abcdef0123)) [#7](https://github.com/o/r/issues/7)\n"
+            ),
+            "{linked}"
+        );
+        let plain = render(None);
+        assert!(plain.contains("\n* bug (abcdef0) #7\n"), "{plain}");
+    }
+
+    #[test]
+    fn link_commits_appends_hash_after_refs() {
+        let mut cfg = dummy_cfg();
+        cfg.link_commits = true;
+        let mut c = mk_commit("feat", "add");
+        c.raw.id = "abcdef0123".into();
+        c.raw.short_id = "abcdef0".into();
+        c.breaking = true;
+        c.issues = vec![7].into();
+        let commits = vec![c];
+        let repo = Repository::parse("https://github.com/o/r.git").unwrap();
+        let render = |repo| {
+            render_release_block(&RenderContext {
+                repo,
+                ..ctx(&cfg, &commits)
+            })
+        };
+        let linked = render(Some(&repo));
+        assert!(
+            linked.contains(
+                "\n* ✨: add (BREAKING) ([#7](https://github.com/o/r/issues/7)) ([abcdef0](https://github.com/o/r/commit/abcdef0123))\n"
+            ),
+            "{linked}"
+        );
+        let plain = render(None);
+        assert!(
+            plain.contains("\n* ✨: add (BREAKING) (#7) (abcdef0)\n"),
+            "{plain}"
+        );
+    }
+
+    #[test]
+    fn link_refs_disabled_per_type() {
+        let mut cfg = dummy_cfg();
+        for tc in &mut cfg.types {
+            if tc.key == "docs" {
+                tc.link_refs = false;
+            }
+        }
+        let mut feat = mk_commit("feat", "add");
+        feat.issues = vec![12].into();
+        let mut docs = mk_commit("docs", "explain");
+        docs.issues = vec![123].into();
+        let commits = vec![feat, docs];
+        let repo = Repository::parse("https://github.com/o/r.git").unwrap();
+        let txt = render_release_block(&RenderContext {
+            repo: Some(&repo),
+            ..ctx(&cfg, &commits)
+        });
+        assert!(
+            txt.contains("\n* ✨: add ([#12](https://github.com/o/r/issues/12))\n"),
+            "{txt}"
+        );
+        assert!(txt.contains("\n* 📚: explain (#123)\n"), "{txt}");
+    }
+
+    #[test]
+    fn empty_emoji_leaves_no_stray_spaces() {
+        let mut cfg = dummy_cfg();
+        for tc in &mut cfg.types {
+            tc.emoji = EcoString::new();
+        }
+        let mut scoped = mk_commit("fix", "bug");
+        scoped.scope = Some("api".into());
+        let commits = vec![mk_commit("feat", "add"), scoped];
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains("\n### Features\n"), "{txt}");
+        assert!(txt.contains("\n* add\n"), "{txt}");
+        assert!(txt.contains("\n### Bug Fixes\n"), "{txt}");
+        assert!(txt.contains("\n* (api): bug\n"), "{txt}");
+    }
+
+    #[test]
+    fn type_label_replaces_missing_emoji() {
+        let mut cfg = dummy_cfg();
+        for tc in &mut cfg.types {
+            tc.emoji = EcoString::new();
+        }
+        cfg.show_type_label = true;
+        let mut scoped = mk_commit("fix", "bug");
+        scoped.scope = Some("api".into());
+        let commits = vec![mk_commit("feat", "add"), scoped];
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains("\n### Features\n* feat: add\n"), "{txt}");
+        assert!(txt.contains("\n* fix(api): bug\n"), "{txt}");
+    }
+
+    #[test]
+    fn emoji_toggle_keeps_titles_and_order() {
+        let mut scoped = mk_commit("fix", "bug");
+        scoped.scope = Some("api".into());
+        let commits = vec![mk_commit("feat", "add"), scoped];
+        let mut cfg = dummy_cfg();
+        let with = render(&cfg, &commits);
+        cfg.emoji = false;
+        let without = render(&cfg, &commits);
+        assert_eq!(
+            with,
+            "## v1.0.0\n\n### ✨ Features\n* ✨: add\n\n### 🐞 Bug Fixes\n* 🐞(api): bug\n"
+        );
+        assert_eq!(
+            without,
+            "## v1.0.0\n\n### Features\n* add\n\n### Bug Fixes\n* (api): bug\n"
+        );
+    }
+
+    #[test]
+    fn sections_above_threshold_collapse() {
+        let mut cfg = dummy_cfg();
+        cfg.collapse_threshold = Some(2);
+        let commits: Vec<ParsedCommit> = ["one", "two", "three"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, desc)| {
+                let mut c = mk_commit("fix", desc);
+                c.index = i;
+                c
+            })
+            .chain([mk_commit("feat", "add")])
+            .collect();
+        let rc = ctx(&cfg, &commits);
+        let txt = render_release_block(&rc);
+        assert_eq!(
+            txt,
+            "## v1.0.0\n\n### ✨ Features\n* ✨: add\n\n\
+             <details><summary>### 🐞 Bug Fixes (3)</summary>\n\n\
+             * 🐞: one\n* 🐞: two\n* 🐞: three\n\n</details>\n"
+        );
+        assert_eq!(render_release_block(&rc), txt);
+    }
+
+    #[test]
+    fn hide_reverted_drops_revert_pairs() {
+        let mut added = mk_commit("feat", "add thing");
+        added.raw.id = "0123456789abcdef".into();
+        added.raw.short_id = "0123456".into();
+        added.index = 0;
+        let mut kept = mk_commit("fix", "bug");
+        kept.raw.id = "fedcba9876543210".into();
+        kept.index = 1;
+        let mut revert = mk_commit("revert", "feat: add thing");
+        revert.reverts = Some("0123456789abcdef".into());
+        revert.index = 2;
+        let mut old_revert = mk_commit("revert", "feat: older thing");
+        old_revert.reverts = Some("aaaaaaa".into());
+        old_revert.index = 3;
+        let commits = vec![added, kept, revert, old_revert];
+        let mut cfg = dummy_cfg();
+        let shown = render(&cfg, &commits);
+        assert!(shown.contains("* ✨: add thing\n"), "{shown}");
+        assert!(shown.contains("* ⏪: Revert: feat: add thing\n"), "{shown}");
+
+        cfg.hide_reverted = true;
+        let hidden = render(&cfg, &commits);
+        assert!(!hidden.contains("add thing"), "{hidden}");
+        assert!(!hidden.contains("### ✨ Features"), "{hidden}");
+        assert!(hidden.contains("* 🐞: bug\n"), "{hidden}");
+        assert!(
+            hidden.contains("* ⏪: Revert: feat: older thing\n"),
+            "{hidden}"
+        );
+    }
+
+    #[test]
+    fn contributors_grouped_by_email_domain() {
+        let mut cfg = dummy_cfg();
+        cfg.contributor_groups = [("corp.com", "Corp"), ("partner.io", "Partner")]
+            .into_iter()
+            .map(|(domain, label)| (domain.into(), label.into()))
+            .collect();
+        let authored = |name: &str, email: &str| {
+            let mut c = mk_commit("fix", "bug");
+            c.raw.author_name = name.into();
+            c.raw.author_email = email.into();
+            c
+        };
+        let commits = vec![
+            authored("Zed", "zed@Corp.com"),
+            authored("Pat", "pat@partner.io"),
+            authored("Sol", "sol@gmail.com"),
+            authored("Ann", "ann@corp.com"),
+        ];
+        let opts = crate::authors::AuthorOptions {
+            hide_author_email: true,
+            ..Default::default()
+        };
+        let authors = Authors::collect(&commits, &opts);
+        let txt = render_release_block(&RenderContext {
+            authors: Some(&authors),
+            ..ctx(&cfg, &commits)
+        });
+        assert!(
+            txt.ends_with(
+                "### Contributors\n\n#### Corp\n- Zed\n- Ann\n\n#### Partner\n- Pat\n\n#### Other\n- Sol\n"
+            ),
+            "{txt}"
+        );
+    }
+
+    #[test]
+    fn hidden_breaking_commits_get_own_section() {
+        let mut cfg = dummy_cfg();
+        for tc in &mut cfg.types {
+            if tc.key == "chore" {
+                tc.enabled = false;
+            }
+        }
+        let mut breaking = mk_commit("chore", "drop node 16");
+        breaking.breaking = true;
+        breaking.index = 1;
+        let mut plain = mk_commit("chore", "bump lockfile");
+        plain.index = 2;
+        let commits = vec![mk_commit("feat", "add"), breaking, plain];
+        let txt = render(&cfg, &commits);
+        assert!(
+            txt.contains("### ⚠️ Breaking Changes\n* chore: drop node 16\n"),
+            "{txt}"
+        );
+        assert!(!txt.contains("Chores"), "{txt}");
+        assert!(!txt.contains("bump lockfile"), "{txt}");
+
+        cfg.breaking_section_title = EcoString::new();
+        assert!(!render(&cfg, &commits).contains("drop node 16"));
+    }
+
+    #[test]
+    fn grouped_breaking_section_lists_all_breaking_commits() {
+        let mut cfg = dummy_cfg();
+        cfg.group_breaking = true;
+        let mut api = mk_commit("feat", "new api");
+        api.breaking = true;
+        api.index = 2;
+        api.footers = vec![(
+            "BREAKING CHANGE".into(),
+            "old endpoints removed\nuse /v2".into(),
+        )]
+        .into();
+        let mut fix = mk_commit("fix", "strict parsing");
+        fix.breaking = true;
+        fix.index = 1;
+        let commits = vec![api, fix, mk_commit("fix", "typo")];
+        let txt = render(&cfg, &commits);
+        assert!(
+            txt.contains(
+                "### ⚠️ Breaking Changes\n* fix: strict parsing\n* feat: new api\n  old endpoints removed\n  use /v2\n\n### "
+            ),
+            "{txt}"
+        );
+        assert!(txt.contains("* ✨: new api (BREAKING)\n"), "{txt}");
+
+        cfg.dedupe_breaking = true;
+        let txt = render(&cfg, &commits);
+        assert!(!txt.contains("(BREAKING)"), "{txt}");
+        assert!(!txt.contains("Features"), "{txt}");
+        assert!(txt.contains("* 🐞: typo\n"), "{txt}");
+    }
+
+    #[test]
+    fn sections_sorted_by_impact() {
+        let mut cfg = dummy_cfg();
+        let commits = vec![
+            mk_commit("docs", "guide"),
+            mk_commit("fix", "bug"),
+            mk_commit("refactor", "tidy"),
+            mk_commit("feat", "add"),
+        ];
+        let sections = |cfg: &ResolvedConfig| {
+            let txt = render(cfg, &commits);
+            txt.lines()
+                .filter_map(|l| l.strip_prefix("### "))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            sections(&cfg),
+            [
+                "✨ Features",
+                "🐞 Bug Fixes",
+                "📚 Documentation",
+                "🛠 Refactors"
+            ]
+        );
+        // Give docs a major impact so it must jump ahead of features
+        for tc in &mut cfg.types {
+            if tc.key == "docs" {
+                tc.semver = SemverImpact::Major;
+            }
+        }
+        cfg.section_sort = SectionSort::Impact;
+        assert_eq!(
+            sections(&cfg),
+            [
+                "📚 Documentation",
+                "✨ Features",
+                "🐞 Bug Fixes",
+                "🛠 Refactors"
+            ]
+        );
+    }
+
+    #[test]
+    fn issue_refs_truncated() {
+        let mut cfg = dummy_cfg();
+        let mut c = mk_commit("fix", "many");
+        c.issues = (1..=30).collect();
+        let commits = vec![c];
+        assert!(render(&cfg, &commits).contains("#29, #30)"));
+        cfg.max_refs_per_entry = Some(3);
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains("* 🐞: many (#1, #2, #3, +27 more)\n"), "{txt}");
+        cfg.max_refs_per_entry = Some(0);
+        assert!(render(&cfg, &commits).contains("* 🐞: many (+30 more)\n"));
+    }
+
+    #[test]
+    fn closed_issues_marked_when_enabled() {
+        let mut cfg = dummy_cfg();
+        let mut c = mk_commit("fix", "crash");
+        c.issues = vec![3, 8].into();
+        c.closed_issues = vec![8].into();
+        let commits = vec![c];
+        let repo = Repository::parse("https://github.com/o/r.git").unwrap();
+        let render = |cfg: &ResolvedConfig| {
+            render_release_block(&RenderContext {
+                repo: Some(&repo),
+                ..ctx(cfg, &commits)
+            })
+        };
+        let plain = render(&cfg);
+        assert!(plain.contains("* 🐞: crash ([#3](https://github.com/o/r/issues/3), [#8](https://github.com/o/r/issues/8))\n"), "{plain}");
+        cfg.show_closed_issues = true;
+        let marked = render(&cfg);
+        assert!(marked.contains("* 🐞: crash ([#3](https://github.com/o/r/issues/3), closes [#8](https://github.com/o/r/issues/8))\n"), "{marked}");
+    }
+
+    #[test]
+    fn listed_footers_rendered_on_entry() {
+        let mut cfg = dummy_cfg();
+        let mut c = mk_commit("fix", "crash");
+        c.footers = vec![
+            ("Reviewed-by".into(), "Ann".into()),
+            ("Tested-by".into(), "Bob".into()),
+            ("reviewed-by".into(), "Cy".into()),
+        ]
+        .into();
+        let commits = vec![c];
+        assert!(render(&cfg, &commits).contains("* 🐞: crash\n"));
+        cfg.render_footers = vec![EcoString::from("Reviewed-By")].into();
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains("* 🐞: crash — reviewed by Ann, Cy\n"), "{txt}");
+        assert!(!txt.contains("Bob"));
+    }
+
+    #[test]
+    fn body_bullets_expanded_as_sub_items() {
+        let mut cfg = dummy_cfg();
+        let mut c = mk_commit("feat", "squashed work (#7)");
+        c.body = "Summary paragraph.\n\n- add parser\n* wire config\n  across layers\n    - nested detail\n- update docs\n\nTrailing text."
+            .into();
+        let commits = vec![c];
+        assert!(!render(&cfg, &commits).contains("add parser"));
+        cfg.expand_body_bullets = true;
+        let txt = render(&cfg, &commits);
+        assert!(
+            txt.contains(
+                "squashed work (#7)\n  - add parser\n  - wire config across layers\n  - update docs\n"
+            ),
+            "{txt}"
+        );
+        assert!(!txt.contains("nested detail"));
+        assert!(!txt.contains("Summary paragraph"));
+    }
+
+    #[test]
+    fn pr_labels_rendered_as_badges() {
+        let cfg = dummy_cfg();
+        let mut c = mk_commit("feat", "add (#42)");
+        c.pr = Some(42);
+        c.labels = vec![EcoString::from("enhancement")].into();
+        let commits = vec![c];
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains("* ✨: add (#42) [enhancement]"));
+    }
+
+    #[test]
+    fn revert_entries_show_prefixed_subject() {
+        let mut cfg = dummy_cfg();
+        let commits = vec![
+            mk_commit("revert", "feat: add login"),
+            mk_commit("revert", "\"fix: cache bug\""),
+        ];
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains("* ⏪: Revert: feat: add login\n"));
+        assert!(txt.contains("* ⏪: Revert: fix: cache bug\n"));
+
+        cfg.revert_prefix = EcoString::new();
+        assert!(render(&cfg, &commits).contains("* ⏪: feat: add login\n"));
+    }
+
+    #[test]
+    fn migration_rendered_as_blockquote() {
+        let cfg = dummy_cfg();
+        let mut c = mk_commit("feat", "new config format");
+        c.breaking = true;
+        c.migration = Some("Rename `a` to `b`.\n\nRun `tool migrate`.".into());
+        let commits = vec![c];
+        let txt = render(&cfg, &commits);
+        assert!(txt.contains(
+            "* ✨: new config format (BREAKING)\n  > Rename `a` to `b`.\n  >\n  > Run `tool migrate`.\n"
+        ));
+    }
+
+    #[test]
+    fn range_line_under_heading() {
+        let mut cfg = dummy_cfg();
+        cfg.show_range = true;
+        let commits = vec![mk_commit("feat", "add"), mk_commit("fix", "bug")];
+        let version = semver::Version::parse("1.3.0").unwrap();
+        let render = |previous_tag, first_commit| {
+            render_release_block(&RenderContext {
+                version: &version,
+                previous_tag,
+                first_commit,
+                ..ctx(&cfg, &commits)
+            })
+        };
+        assert!(
+            render(Some("v1.2.0"), None)
+                .starts_with("## v1.3.0\n_Changes from v1.2.0 to v1.3.0 (2 commits)_\n")
+        );
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        assert!(
+            render(None, Some(sha))
+                .starts_with("## v1.3.0\n_Changes from 0123456 to v1.3.0 (2 commits)_\n")
+        );
+    }
+
+    #[test]
+    fn first_commit_compare_base_shortened() {
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        let mut cfg = dummy_cfg();
+        let repo = Repository::parse("https://github.com/o/r.git").unwrap();
+        let commits = vec![mk_commit("feat", "add")];
+        let version = semver::Version::parse("0.1.0").unwrap();
+        let previous = semver::Version::parse("0.0.0").unwrap();
+        let render = |cfg: &ResolvedConfig| {
+            render_release_block(&RenderContext {
+                version: &version,
+                previous_version: Some(&previous),
+                repo: Some(&repo),
+                first_commit: Some(sha),
+                ..ctx(cfg, &commits)
+            })
+        };
+        assert!(render(&cfg).contains("/compare/0123456...v0.1.0)"));
+        cfg.compare_short_sha = false;
+        assert!(render(&cfg).contains(&format!("/compare/{sha}...v0.1.0)")));
+    }
+
+    #[test]
+    fn compare_link_can_be_disabled() {
+        let mut cfg = dummy_cfg();
+        let repo = Repository::parse("https://github.com/o/r.git").unwrap();
+        let commits = vec![mk_commit("feat", "add")];
+        let version = semver::Version::parse("0.2.0").unwrap();
+        let previous = semver::Version::parse("0.1.0").unwrap();
+        let render = |cfg: &ResolvedConfig| {
+            render_release_block(&RenderContext {
+                version: &version,
+                previous_version: Some(&previous),
+                repo: Some(&repo),
+                previous_tag: Some("v0.1.0"),
+                ..ctx(cfg, &commits)
+            })
+        };
+        assert!(render(&cfg).contains("/compare/v0.1.0...v0.2.0)"));
+        cfg.compare_link = false;
+        let txt = render(&cfg);
+        assert!(!txt.contains("compare"), "{txt}");
+        assert!(txt.starts_with("## v0.2.0\n"));
+    }
+
+    #[test]
+    fn rst_underlines_sections_and_uses_rst_links() {
+        let cfg = dummy_cfg();
+        let mut feat = mk_commit("feat", "add api");
+        feat.issues = vec![12].into();
+        let commits = vec![feat, mk_commit("fix", "bug")];
+        let repo = Repository::parse("https://github.com/o/r.git").unwrap();
+        let rc = RenderContext {
+            version: &semver::Version::parse("1.1.0").unwrap(),
+            previous_version: Some(&semver::Version::parse("1.0.0").unwrap()),
+            repo: Some(&repo),
+            previous_tag: Some("v1.0.0"),
+            ..ctx(&cfg, &commits)
+        };
+        let txt = render_release_block_as(&rc, &Rst);
+        assert_eq!(
+            txt,
+            "v1.1.0\n======\n\n\
+             `compare changes <https://github.com/o/r/compare/v1.0.0...v1.1.0>`__\n\
+             \n✨ Features\n-----------\n\n\
+             * ✨: add api (`#12 <https://github.com/o/r/issues/12>`__)\n\
+             \n🐞 Bug Fixes\n------------\n\n\
+             * 🐞: bug\n"
+        );
+        // The markdown rendering of the same block is unchanged
+        let md = render_release_block_as(&rc, &Markdown);
+        assert_eq!(md, render_release_block(&rc));
+        assert!(
+            md.contains("### ✨ Features\n* ✨: add api ([#12](https://github.com/o/r/issues/12))")
+        );
+    }
 }
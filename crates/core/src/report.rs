@@ -0,0 +1,104 @@
+use crate::conventional::BreakingSource;
+use crate::parse::{BumpKind, ParsedCommit, commit_impact};
+use ecow::EcoString;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Machine-readable classification report for a release range.
+///
+/// Lists every commit that made it into the changelog together with its
+/// classification and the version bump it contributes on its own.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub summary: ReportSummary,
+    pub commits: Vec<ReportCommit>,
+}
+
+/// Overall outcome of version inference.
+#[derive(Debug, Serialize)]
+pub struct ReportSummary {
+    pub previous_version: semver::Version,
+    pub version: semver::Version,
+    pub bump: BumpKind,
+    pub commit_count: usize,
+    /// Entries listed under each enabled type section, keyed by type
+    pub sections: BTreeMap<EcoString, usize>,
+}
+
+/// Classification of a single commit.
+#[derive(Debug, Serialize)]
+pub struct ReportCommit {
+    pub id: EcoString,
+    pub short_id: EcoString,
+    pub summary: EcoString,
+    pub r#type: EcoString,
+    pub scope: Option<EcoString>,
+    pub breaking: bool,
+    /// What made the commit breaking (`marker` or `footer`)
+    pub breaking_source: Option<BreakingSource>,
+    pub conventional: bool,
+    /// Bump this commit would cause on its own
+    pub impact: BumpKind,
+    /// Type section the commit is listed under (`None` for disabled or unknown types)
+    pub section: Option<EcoString>,
+    /// Whether the commit carries the highest impact of the range
+    pub drives_bump: bool,
+}
+
+impl Report {
+    /// Build a report from classified commits and the inferred version.
+    pub fn new(
+        previous_version: &semver::Version,
+        version: &semver::Version,
+        bump: BumpKind,
+        commits: &[ParsedCommit],
+    ) -> Self {
+        let top = commits
+            .iter()
+            .fold(BumpKind::None, |acc, c| acc.escalate(commit_impact(c)));
+        let section = |c: &ParsedCommit| {
+            c.type_cfg
+                .as_ref()
+                .filter(|tc| tc.enabled)
+                .map(|tc| tc.key.clone())
+        };
+        let mut sections = BTreeMap::new();
+        for key in commits.iter().filter_map(section) {
+            *sections.entry(key).or_default() += 1;
+        }
+        Self {
+            summary: ReportSummary {
+                previous_version: previous_version.clone(),
+                version: version.clone(),
+                bump,
+                commit_count: commits.len(),
+                sections,
+            },
+            commits: commits
+                .iter()
+                .map(|c| ReportCommit {
+                    id: c.raw.id.clone(),
+                    short_id: c.raw.short_id.clone(),
+                    summary: c.raw.summary.clone(),
+                    r#type: c.r#type.clone(),
+                    scope: c.scope.clone(),
+                    breaking: c.breaking,
+                    breaking_source: c.breaking_source,
+                    conventional: c.conventional,
+                    impact: commit_impact(c),
+                    section: section(c),
+                    drives_bump: top != BumpKind::None && commit_impact(c) == top,
+                })
+                .collect(),
+        }
+    }
+
+    /// Write the report as pretty-printed JSON to `path`.
+    pub async fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        tokio::fs::write(path, json).await?;
+        Ok(())
+    }
+}
@@ -78,24 +78,32 @@ impl Repository {
         None
     }
 
+    /// Build from a host and `owner/name` path.
+    ///
+    /// GitLab paths may nest subgroups (`group/subgroup/project`); everything
+    /// before the last segment becomes the owner namespace.
     fn from_host_path(host: EcoString, path: &str, original: &str) -> Option<Self> {
         let path = path.trim_end_matches('/').trim_end_matches(".git");
-        let mut segs = path.split('/');
-        let owner = segs.next()?.into();
-        let name = segs.next()?.into();
-        if segs.next().is_some() {
-            return None;
-        } // extra segments unsupported (subgroups future)
         let provider = match host.as_str() {
             "github.com" => Provider::GitHub,
+            // GitHub Enterprise instances conventionally live on github.<domain>
+            h if h.starts_with("github.") => Provider::GitHub,
             "gitlab.com" => Provider::GitLab,
             "bitbucket.org" => Provider::Bitbucket,
             _ => Provider::Other,
         };
+        let (owner, name) = path.rsplit_once('/')?;
+        let nested = owner.contains('/');
+        if name.is_empty()
+            || owner.split('/').any(str::is_empty)
+            || (nested && provider != Provider::GitLab)
+        {
+            return None;
+        }
         Some(Self {
             host,
-            owner,
-            name,
+            owner: owner.into(),
+            name: name.into(),
             provider,
             original: original.into(),
         })
@@ -183,14 +191,22 @@ impl Repository {
     }
 }
 
+/// Format an issue, pull request or commit reference as a markdown link.
+///
+/// Commit hashes are displayed abbreviated but link to the hash as given, so
+/// passing the full SHA keeps links unambiguous.
 pub fn format_reference(repo: Option<&Repository>, kind: ReferenceKind, raw: &str) -> EcoString {
+    let text = match kind {
+        ReferenceKind::Hash => short_sha(raw),
+        _ => raw,
+    };
     let Some(r) = repo else {
-        return raw.into();
+        return text.into();
     };
     let (segment, display) = match kind {
         ReferenceKind::PullRequest => match r.provider {
             Provider::GitHub => ("pull", raw.trim_start_matches('#')),
-            Provider::GitLab => ("merge_requests", raw.trim_start_matches('#')),
+            Provider::GitLab => ("merge_requests", raw.trim_start_matches(['#', '!'])),
             Provider::Bitbucket => ("pull-requests", raw.trim_start_matches('#')),
             Provider::Other => return raw.into(),
         },
@@ -198,12 +214,12 @@ pub fn format_reference(repo: Option<&Repository>, kind: ReferenceKind, raw: &st
         ReferenceKind::Hash => match r.provider {
             Provider::GitHub | Provider::GitLab => ("commit", raw),
             Provider::Bitbucket => ("commits", raw),
-            Provider::Other => return raw.into(),
+            Provider::Other => return text.into(),
         },
     };
     format!(
         "[{}](https://{}/{}/{}/{}/{})",
-        raw, r.host, r.owner, r.name, segment, display
+        text, r.host, r.owner, r.name, segment, display
     )
     .into()
 }
@@ -230,6 +246,18 @@ pub fn format_compare_changes(
     Some(format!("[compare changes]({})", url).into())
 }
 
+/// Abbreviate a full 40-character hex commit SHA to 7 characters.
+///
+/// Anything that is not a full SHA (tags, branch names, already short SHAs)
+/// is returned unchanged.
+pub fn short_sha(rev: &str) -> &str {
+    if rev.len() == 40 && rev.bytes().all(|b| b.is_ascii_hexdigit()) {
+        &rev[..7]
+    } else {
+        rev
+    }
+}
+
 impl fmt::Display for Repository {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}:{}/{}", self.host, self.owner, self.name)
@@ -264,6 +292,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_gitlab_subgroup() {
+        let r = Repository::parse("https://gitlab.com/group/subgroup/project.git").unwrap();
+        assert_eq!(r.provider, Provider::GitLab);
+        assert_eq!(r.owner, "group/subgroup");
+        assert_eq!(r.name, "project");
+        assert_eq!(
+            r.issue_url(7),
+            "https://gitlab.com/group/subgroup/project/issues/7"
+        );
+
+        let ssh = Repository::parse("git@gitlab.com:group/subgroup/project.git").unwrap();
+        assert_eq!(
+            ssh,
+            Repository {
+                original: ssh.original.clone(),
+                ..r
+            }
+        );
+        assert!(Repository::parse("https://github.com/a/b/c").is_none());
+    }
+
+    #[test]
+    fn commit_reference_links_full_sha() {
+        let r = Repository::parse("https://github.com/o/r.git").unwrap();
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        assert_eq!(
+            format_reference(Some(&r), ReferenceKind::Hash, sha),
+            format!("[0123456](https://github.com/o/r/commit/{sha})")
+        );
+        assert_eq!(format_reference(None, ReferenceKind::Hash, sha), "0123456");
+    }
+
     #[test]
     fn compare_bitbucket() {
         let r = Repository {
@@ -16,3 +16,8 @@ where
     }
     out
 }
+
+/// Today's date in the local timezone as `YYYY-MM-DD`.
+pub fn today() -> String {
+    gix::date::Time::now_local_or_utc().format(gix::date::time::format::SHORT)
+}
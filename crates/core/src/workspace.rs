@@ -0,0 +1,93 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Enumerate the members of the Cargo workspace rooted at `root`.
+///
+/// Reads `workspace.members` from the root Cargo.toml. Entries ending in `/*`
+/// expand to every direct subdirectory containing a Cargo.toml; entries listed
+/// in `workspace.exclude` are skipped.
+///
+/// # Arguments
+/// * `root` - Directory containing the workspace Cargo.toml
+///
+/// # Returns
+/// * `Ok(members)` - Absolute member directories, sorted and deduplicated
+/// * `Err` - Missing or unparsable Cargo.toml, or no `[workspace]` table
+pub fn members(root: &Path) -> Result<Vec<PathBuf>> {
+    let manifest = root.join("Cargo.toml");
+    let txt =
+        std::fs::read_to_string(&manifest).with_context(|| format!("Reading {manifest:?}"))?;
+    let doc: toml_edit::DocumentMut = txt
+        .parse()
+        .with_context(|| format!("Parsing TOML {manifest:?}"))?;
+    let workspace = doc
+        .get("workspace")
+        .ok_or_else(|| anyhow::anyhow!("{manifest:?} has no [workspace] table"))?;
+    let strings = |key: &str| -> Vec<String> {
+        workspace
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let excluded: Vec<PathBuf> = strings("exclude").iter().map(|e| root.join(e)).collect();
+
+    let mut out = Vec::new();
+    for entry in strings("members") {
+        if let Some(parent) = entry.strip_suffix("/*") {
+            let dir = root.join(parent);
+            for child in std::fs::read_dir(&dir).with_context(|| format!("Reading {dir:?}"))? {
+                let path = child?.path();
+                if path.join("Cargo.toml").is_file() {
+                    out.push(path);
+                }
+            }
+        } else {
+            let path = root.join(&entry);
+            if path.join("Cargo.toml").is_file() {
+                out.push(path);
+            } else {
+                tracing::warn!(member = %entry, "workspace member has no Cargo.toml; skipping");
+            }
+        }
+    }
+    out.retain(|p| !excluded.contains(p));
+    out.sort();
+    out.dedup();
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn expands_globs_and_skips_excluded() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\", \"tools/cli\"]\nexclude = [\"crates/skip\"]\n",
+        )
+        .unwrap();
+        for member in ["crates/b", "crates/a", "crates/skip", "tools/cli"] {
+            std::fs::create_dir_all(root.join(member)).unwrap();
+            std::fs::write(root.join(member).join("Cargo.toml"), "[package]\n").unwrap();
+        }
+        std::fs::create_dir_all(root.join("crates/not-a-crate")).unwrap();
+        let found = members(root).unwrap();
+        assert_eq!(
+            found,
+            vec![
+                root.join("crates/a"),
+                root.join("crates/b"),
+                root.join("tools/cli")
+            ]
+        );
+    }
+}
@@ -261,6 +261,8 @@ fn test_resolve_github_handles_structure() {
         list: EcoVec::from(vec![Author {
             name: EcoString::from("Alice"),
             email: Some(EcoString::from("alice@example.com")),
+            domain: Some("example.com".into()),
+            first_time: false,
         }]),
         suppressed: false,
     };
@@ -280,3 +282,47 @@ fn test_author_options_default() {
     assert!(opts.github_token.is_none());
     assert!(!opts.enable_github_aliasing);
 }
+
+#[tokio::test]
+async fn test_authors_file_excludes_and_aliases() {
+    use novalyn_core::authors::AuthorsFile;
+
+    let td = TempDir::new().unwrap();
+    let path = td.path().join("authors.toml");
+    std::fs::write(
+        &path,
+        "exclude = [\"bot@example.com\"]\n\n[aliases]\n\"alice@old.example.com\" = \"alice@example.com\"\n",
+    )
+    .unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = vec![
+        mk_commit("Alice", "alice@old.example.com", &[]),
+        mk_commit("Alice", "alice@example.com", &[]),
+        mk_commit("Bot", "bot@example.com", &[]),
+    ];
+    let parsed = parse_and_classify(commits.into(), &cfg);
+
+    let mut opts = AuthorOptions::default();
+    AuthorsFile::load(&path).await.unwrap().apply(&mut opts);
+    let authors = Authors::collect(&parsed, &opts);
+    assert_eq!(
+        authors.list.as_slice(),
+        [Author {
+            name: "Alice".into(),
+            email: Some("alice@example.com".into()),
+            domain: Some("example.com".into()),
+            first_time: false,
+        }]
+    );
+
+    // JSON files are accepted too
+    let json = td.path().join("authors.json");
+    std::fs::write(&json, r#"{"exclude": ["Alice"]}"#).unwrap();
+    let mut opts = AuthorOptions::default();
+    AuthorsFile::load(&json).await.unwrap().apply(&mut opts);
+    assert_eq!(opts.exclude.as_slice(), [EcoString::from("Alice")]);
+}
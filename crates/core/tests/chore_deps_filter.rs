@@ -45,3 +45,118 @@ fn keeps_breaking_chore_deps() {
     assert_eq!(parsed.len(), 1);
     assert!(parsed[0].breaking);
 }
+
+#[test]
+fn drops_commits_with_skip_marker() {
+    let td = tempfile::tempdir().unwrap();
+    let mut cfg = novalyn_core::config::load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = vec![mk("feat: x [skip changelog]"), mk("feat: y [skip-cl]")];
+    let parsed = parse_and_classify(commits.clone().into(), &cfg);
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].raw.summary, "feat: y [skip-cl]");
+
+    cfg.skip_marker = "[skip-cl]".into();
+    let parsed = parse_and_classify(commits.into(), &cfg);
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].raw.summary, "feat: x [skip changelog]");
+}
+
+#[test]
+fn chore_deps_filter_can_be_disabled() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "filter_chore_deps = false\n",
+    )
+    .unwrap();
+    let cfg = novalyn_core::config::load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = vec![mk("chore(deps): bump"), mk("chore(other): keep")];
+    let parsed = parse_and_classify(commits.into(), &cfg);
+    assert_eq!(parsed.len(), 2);
+}
+
+#[test]
+fn drops_commits_matching_skip_patterns() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "skip_commit_patterns = ['^chore: sync translations', '\\[bot\\]$', '(']\n",
+    )
+    .unwrap();
+    let cfg = novalyn_core::config::load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(cfg.skip_commit_patterns.len(), 2);
+    assert!(
+        cfg.warnings
+            .iter()
+            .any(|w| w.contains("Invalid skip_commit_patterns regex '('"))
+    );
+    let commits = vec![
+        mk("chore: sync translations from crowdin"),
+        mk("fix: update lockfile [bot]"),
+        mk("feat: real work"),
+    ];
+    let parsed = parse_and_classify(commits.into(), &cfg);
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].raw.summary, "feat: real work");
+}
+
+#[test]
+fn drops_prior_release_commits() {
+    let td = tempfile::tempdir().unwrap();
+    let load = || {
+        novalyn_core::config::load_config(LoadOptions {
+            cwd: td.path(),
+            cli_overrides: None,
+        })
+        .unwrap()
+    };
+    // The default template applies even with the chore(deps) rule off
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "filter_chore_deps = false\n",
+    )
+    .unwrap();
+    let commits = vec![
+        mk("chore(release): v1.2.0"),
+        mk("chore(release): v2.0.0-rc.1"),
+        mk("chore(release): v1.2.0 and more"),
+        mk("feat: real work"),
+    ];
+    let parsed = parse_and_classify(commits.into(), &load());
+    let kept: Vec<_> = parsed.iter().map(|c| c.raw.summary.as_str()).collect();
+    assert_eq!(kept, ["chore(release): v1.2.0 and more", "feat: real work"]);
+
+    // A template that isn't a chore is dropped too
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "release_commit_template = 'feat(release): ship {{crateName}} {{newVersion}}'\n",
+    )
+    .unwrap();
+    let commits = vec![
+        mk("feat(release): ship demo 0.3.1"),
+        mk("feat(release): ship demo soon"),
+    ];
+    let parsed = parse_and_classify(commits.into(), &load());
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].raw.summary, "feat(release): ship demo soon");
+
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "release_commit_template = ''\n",
+    )
+    .unwrap();
+    let parsed = parse_and_classify(vec![mk("chore(release): v1.2.0")].into(), &load());
+    assert_eq!(parsed.len(), 1);
+}
@@ -46,8 +46,10 @@ fn precedence_cli_over_file() {
                 "feat".into(),
                 config::TypeToggleOrConfig::Config(config::TypeConfigPartial {
                     title: Some("Features B".into()),
+                    singular_title: None,
                     emoji: None,
                     semver: None,
+                    link_refs: None,
                 }),
             );
             m
@@ -101,3 +103,247 @@ fn env_token_precedence() {
     .unwrap();
     assert_eq!(cfg_high.github_token.as_deref(), Some("gh_high"));
 }
+
+#[test]
+fn tag_format_round_trips() {
+    let dir = temp_dir();
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        "tag_prefix = \"ignored-\"\ntag_format = \"myproj/v{{newVersion}}-release\"\n",
+    )
+    .unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let version = semver::Version::parse("1.2.3").unwrap();
+    assert_eq!(cfg.tag_name(&version), "myproj/v1.2.3-release");
+    assert_eq!(cfg.parse_tag("myproj/v1.2.3-release"), Some(version));
+    assert_eq!(cfg.parse_tag("v1.2.3"), None);
+    assert_eq!(cfg.parse_tag("myproj/v1.2.3"), None);
+}
+
+#[test]
+fn crate_name_read_from_manifest_and_scopes_tags() {
+    let dir = temp_dir();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        "tag_format = \"{{crateName}}-v{{newVersion}}\"\n",
+    )
+    .unwrap();
+    assert_eq!(config::crate_name(dir.path()).as_deref(), Some("my-crate"));
+    let cfg = config::load_config(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(cfg.crate_name.as_deref(), Some("my-crate"));
+    let version = semver::Version::parse("1.2.3").unwrap();
+    assert_eq!(cfg.tag_name(&version), "my-crate-v1.2.3");
+    assert_eq!(cfg.parse_tag("my-crate-v1.2.3"), Some(version));
+    assert_eq!(config::crate_name(temp_dir().path()), None);
+}
+
+#[test]
+fn tag_prefix_is_optional_when_parsing() {
+    let dir = temp_dir();
+    let cfg = config::load_config(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: Some(RawConfig {
+            tag_prefix: Some("rel-".into()),
+            ..Default::default()
+        }),
+    })
+    .unwrap();
+    let version = semver::Version::parse("0.4.0").unwrap();
+    assert_eq!(cfg.tag_name(&version), "rel-0.4.0");
+    assert_eq!(cfg.parse_tag("rel-0.4.0"), Some(version.clone()));
+    assert_eq!(cfg.parse_tag("0.4.0"), Some(version));
+}
+
+#[test]
+fn sources_record_contributed_keys() {
+    let dir = temp_dir();
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        "tag_prefix = \"rel-\"\nintro = \"# Changes\"\n\n[types]\nfeat = false\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"x\"\nversion = \"0.1.0\"\n\n[package.metadata.novalyn]\nintro = \"# History\"\n",
+    )
+    .unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let desc = cfg
+        .describe_sources()
+        .replace(&dir.path().display().to_string(), "<dir>");
+    assert_eq!(
+        desc,
+        "Config sources (lowest precedence first):\n\
+         \x20 1. novalyn.toml (<dir>/novalyn.toml)\n\
+         \x20    contributes: tag_prefix, types.feat\n\
+         \x20    overridden: intro\n\
+         \x20 2. Cargo.toml [package.metadata.novalyn] (<dir>/Cargo.toml)\n\
+         \x20    contributes: intro\n"
+    );
+}
+
+#[tokio::test]
+async fn no_metadata_isolates_file_config() {
+    let dir = temp_dir();
+    fs::write(dir.path().join("novalyn.toml"), "intro = \"# Changes\"\n").unwrap();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"x\"\nversion = \"0.1.0\"\n\n[package.metadata.novalyn]\nintro = \"# History\"\ntag_prefix = \"rel-\"\n",
+    )
+    .unwrap();
+    let load = |cli_overrides| {
+        config::load_config(LoadOptions {
+            cwd: dir.path(),
+            cli_overrides,
+        })
+        .unwrap()
+    };
+    let cfg = load(None);
+    assert_eq!(cfg.intro.as_deref(), Some("# History"));
+    assert_eq!(cfg.tag_prefix, "rel-");
+
+    let cfg = load(Some(RawConfig::without_metadata()));
+    assert_eq!(cfg.intro.as_deref(), Some("# Changes"));
+    assert_eq!(cfg.tag_prefix, "v");
+    let cfg = config::load_config_async(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: Some(RawConfig::without_metadata()),
+    })
+    .await
+    .unwrap();
+    assert_eq!(cfg.intro.as_deref(), Some("# Changes"));
+    assert_eq!(cfg.tag_prefix, "v");
+
+    // The same switch in novalyn.toml
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        "intro = \"# Changes\"\nno_cargo_metadata = true\n",
+    )
+    .unwrap();
+    let cfg = load(None);
+    assert_eq!(cfg.intro.as_deref(), Some("# Changes"));
+    assert!(cfg.warnings.is_empty(), "{:?}", cfg.warnings);
+}
+
+#[test]
+fn novalyn_toml_found_in_parent_directory() {
+    let dir = temp_dir();
+    novalyn_core::git::init_repo(dir.path()).unwrap();
+    fs::write(dir.path().join("novalyn.toml"), "compare_link = false\n").unwrap();
+    let sub = dir.path().join("crates/inner");
+    fs::create_dir_all(&sub).unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: &sub,
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert!(!cfg.compare_link);
+    let source = cfg.source_file.expect("source file recorded");
+    assert_eq!(
+        source,
+        dir.path().canonicalize().unwrap().join("novalyn.toml")
+    );
+}
+
+#[test]
+fn novalyn_toml_search_stops_at_repository_root() {
+    let dir = temp_dir();
+    novalyn_core::git::init_repo(dir.path()).unwrap();
+    fs::write(dir.path().join("novalyn.toml"), "compare_link = false\n").unwrap();
+    // A nested repository must not inherit the outer repository's config
+    let nested = dir.path().join("vendor/other");
+    fs::create_dir_all(&nested).unwrap();
+    novalyn_core::git::init_repo(&nested).unwrap();
+    fs::create_dir(nested.join("src")).unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: &nested.join("src"),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert!(cfg.compare_link);
+    assert_eq!(cfg.source_file, None);
+}
+
+#[test]
+fn starter_config_matches_defaults() {
+    let dir = temp_dir();
+    let starter = config::starter_config();
+    fs::write(dir.path().join("novalyn.toml"), &starter).unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert!(cfg.warnings.is_empty(), "{:?}", cfg.warnings);
+    let summary = |types: &[config::TypeConfigResolved]| {
+        types
+            .iter()
+            .map(|t| (t.key.clone(), t.title.clone(), t.emoji.clone(), t.semver))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(summary(&cfg.types), summary(&config::default_types()));
+
+    // The commented scope_map example is valid once uncommented
+    let (head, example) = starter.split_once("# [scope_map]").unwrap();
+    let example = example.replace("\n# ", "\n");
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        format!("{head}[scope_map]{example}"),
+    )
+    .unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(
+        cfg.scope_map.get("deps").map(|s| s.as_str()),
+        Some("dependencies")
+    );
+    assert_eq!(cfg.scope_patterns.len(), 1);
+}
+
+#[test]
+fn singular_title_from_file() {
+    let dir = temp_dir();
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        "[types.feat]\nsingular_title = 'Feature'\n\n[types.custom]\ntitle = 'Customs'\nsingular_title = 'Custom'\n",
+    )
+    .unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: dir.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let singular = |key: &str| {
+        let t = cfg.types.iter().find(|t| t.key == key).unwrap();
+        (t.title.clone(), t.singular_title.clone())
+    };
+    assert_eq!(
+        singular("feat"),
+        ("Features".into(), Some("Feature".into()))
+    );
+    assert_eq!(singular("fix"), ("Bug Fixes".into(), None));
+    assert_eq!(
+        singular("custom"),
+        ("Customs".into(), Some("Custom".into()))
+    );
+}
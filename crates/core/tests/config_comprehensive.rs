@@ -199,8 +199,10 @@ title = "File Config"
                 novalyn_core::config::TypeToggleOrConfig::Config(
                     novalyn_core::config::TypeConfigPartial {
                         title: Some("CLI Override".into()),
+                        singular_title: None,
                         emoji: None,
                         semver: None,
+                        link_refs: None,
                     },
                 ),
             )]
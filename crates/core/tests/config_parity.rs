@@ -0,0 +1,103 @@
+//! The sync and async config loaders must resolve the same configuration.
+
+use std::fs;
+use std::path::Path;
+
+use novalyn_core::config::{self, LoadOptions, RawConfig};
+
+/// Load `dir` through both loaders and assert the results match.
+///
+/// Warnings are compared as a sorted list since concurrent loads may record
+/// them in a different order.
+async fn assert_loaders_agree(dir: &Path, cli_overrides: Option<RawConfig>) {
+    let sync = config::load_config(LoadOptions {
+        cwd: dir,
+        cli_overrides: cli_overrides.clone(),
+    })
+    .unwrap();
+    let async_ = config::load_config_async(LoadOptions {
+        cwd: dir,
+        cli_overrides,
+    })
+    .await
+    .unwrap();
+
+    let normalized = |mut cfg: config::ResolvedConfig| {
+        let mut warnings = cfg.warnings.to_vec();
+        warnings.sort();
+        cfg.warnings = warnings.into_iter().collect();
+        format!("{cfg:#?}")
+    };
+    assert_eq!(normalized(sync), normalized(async_));
+}
+
+const CARGO_TOML: &str = r#"[package]
+name = "demo"
+version = "0.1.0"
+
+[package.metadata.novalyn]
+compare_link = false
+tag_prefix = "demo-v"
+"#;
+
+#[tokio::test]
+async fn empty_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_loaders_agree(dir.path(), None).await;
+}
+
+#[tokio::test]
+async fn novalyn_toml_and_metadata_layers() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("Cargo.toml"), CARGO_TOML).unwrap();
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        "tag_prefix = \"x\"\nbogus_key = 1\n\n[types]\nchore = false\n\n[scope_map]\n\"re:^api-.*\" = \"api\"\n",
+    )
+    .unwrap();
+    assert_loaders_agree(dir.path(), None).await;
+    assert_loaders_agree(
+        dir.path(),
+        Some(RawConfig {
+            tag_prefix: Some("cli-".into()),
+            ..Default::default()
+        }),
+    )
+    .await;
+    assert_loaders_agree(dir.path(), Some(RawConfig::without_metadata())).await;
+}
+
+#[tokio::test]
+async fn metadata_skipped_by_novalyn_toml() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("Cargo.toml"), CARGO_TOML).unwrap();
+    fs::write(
+        dir.path().join("novalyn.toml"),
+        "no_cargo_metadata = true\n",
+    )
+    .unwrap();
+    assert_loaders_agree(dir.path(), None).await;
+}
+
+#[tokio::test]
+async fn unreadable_files_warn_alike() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("novalyn.toml"), "not = [valid").unwrap();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package.metadata.novalyn]\ncompare_link = \"nope\"\n",
+    )
+    .unwrap();
+    assert_loaders_agree(dir.path(), None).await;
+}
+
+#[tokio::test]
+async fn novalyn_toml_found_in_parent_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    novalyn_core::git::init_repo(dir.path()).unwrap();
+    fs::write(dir.path().join("novalyn.toml"), "show_range = true\n").unwrap();
+    let sub = dir.path().join("crates/demo");
+    fs::create_dir_all(&sub).unwrap();
+    fs::write(sub.join("Cargo.toml"), CARGO_TOML).unwrap();
+    assert_loaders_agree(&sub, None).await;
+}
@@ -0,0 +1,29 @@
+//! GitHub calls made without `init_crypto_provider` install it themselves.
+//!
+//! Kept in its own test binary so no other test installs the provider first.
+
+use novalyn_core::github::get_username_from_email;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn network_call_without_prior_init() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{"login": "testuser", "email": "test@example.com"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let result = get_username_from_email(
+        "test@example.com",
+        Some("test_token"),
+        Some(&mock_server.uri()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, Some("@testuser".into()));
+}
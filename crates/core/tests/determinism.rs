@@ -99,6 +99,7 @@ fn repeated_render_identical() {
         repo: None,
         cfg: &cfg,
         previous_tag: Some("v0.9.0"),
+        first_commit: None,
         current_ref: "HEAD",
     };
 
@@ -112,6 +113,7 @@ fn repeated_render_identical() {
         repo: None,
         cfg: &cfg,
         previous_tag: Some("v0.9.0"),
+        first_commit: None,
         current_ref: "HEAD",
     };
 
@@ -172,6 +174,7 @@ fn repeated_full_pipeline_identical() {
         yes: true,
         github_alias: false,
         github_token: None,
+        ..Default::default()
     };
 
     let opts2 = ReleaseOptions {
@@ -188,6 +191,7 @@ fn repeated_full_pipeline_identical() {
         yes: true,
         github_alias: false,
         github_token: None,
+        ..Default::default()
     };
 
     let opts3 = ReleaseOptions {
@@ -204,6 +208,7 @@ fn repeated_full_pipeline_identical() {
         yes: true,
         github_alias: false,
         github_token: None,
+        ..Default::default()
     };
 
     let outcome1 = run_release(opts1).unwrap();
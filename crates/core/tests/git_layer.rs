@@ -46,12 +46,201 @@ fn commits_between_works() {
     fs::write(td.path().join("b.txt"), "2").unwrap();
     add_and_commit(&mut repo, "feat: two\n\nbody line").unwrap();
     let head = repo.head().unwrap().id().unwrap().to_string();
-    let commits = commits_between(&repo, Some("v0.1.0"), &head).unwrap();
+    let commits = commits_between(&repo, Some("v0.1.0"), &head, None).unwrap();
     assert_eq!(commits.len(), 1);
     assert_eq!(commits[0].summary, "feat: two");
     assert_eq!(commits[0].body.trim(), "body line");
 }
 
+#[test]
+fn commits_between_limit_keeps_most_recent() {
+    let (td, mut repo) = init_repo();
+    for (i, msg) in ["feat: one", "fix: two", "feat: three", "chore: four"]
+        .into_iter()
+        .enumerate()
+    {
+        fs::write(td.path().join(format!("f{i}.txt")), msg).unwrap();
+        add_and_commit(&mut repo, msg).unwrap();
+    }
+    let summaries = |limit| {
+        commits_between(&repo, None, "HEAD", limit)
+            .unwrap()
+            .iter()
+            .map(|c| c.summary.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(summaries(Some(2)), ["feat: three", "chore: four"]);
+    assert_eq!(summaries(Some(10)).len(), 4);
+    assert_eq!(summaries(None).first().unwrap(), "feat: one");
+}
+
+#[test]
+fn commits_iter_matches_commits_between() {
+    let (td, mut repo) = init_repo();
+    fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    create_tag(&mut repo, "v0.1.0", "v0.1.0", true).unwrap();
+    for (i, msg) in ["fix: two", "feat: three\n\nbody", "chore: four"]
+        .into_iter()
+        .enumerate()
+    {
+        fs::write(td.path().join(format!("f{i}.txt")), msg).unwrap();
+        add_and_commit(&mut repo, msg).unwrap();
+    }
+    for from in [None, Some("v0.1.0")] {
+        let eager = commits_between(&repo, from, "HEAD", None).unwrap();
+        let lazy: Vec<_> = commits_iter(&repo, from, "HEAD").unwrap().collect();
+        let ids = |cs: &[RawCommit]| cs.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&lazy), ids(&eager));
+        assert_eq!(lazy.last().unwrap().summary, "chore: four");
+    }
+}
+
+#[test]
+fn commits_between_excludes_paths() {
+    let (td, mut repo) = init_repo();
+    fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    fs::create_dir(td.path().join("docs")).unwrap();
+    fs::write(td.path().join("docs/guide.md"), "guide").unwrap();
+    add_and_commit(&mut repo, "docs: guide only").unwrap();
+    fs::write(td.path().join("docs/guide.md"), "guide v2").unwrap();
+    fs::write(td.path().join("a.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "fix: code and docs").unwrap();
+    let filter = PathFilter {
+        exclude: ["docs/".into()].into_iter().collect(),
+        ..Default::default()
+    };
+    let commits = commits_between_filtered(&repo, None, "HEAD", &filter, None).unwrap();
+    let summaries: Vec<_> = commits.iter().map(|c| c.summary.as_str()).collect();
+    assert_eq!(summaries, ["feat: one", "fix: code and docs"]);
+}
+
+#[test]
+fn commits_between_includes_paths_and_filters_root_commit() {
+    let (td, mut repo) = init_repo();
+    fs::write(td.path().join("README.md"), "root").unwrap();
+    add_and_commit(&mut repo, "chore: init").unwrap();
+    fs::create_dir_all(td.path().join("crates/foo")).unwrap();
+    fs::create_dir_all(td.path().join("crates/bar")).unwrap();
+    fs::write(td.path().join("crates/foo/lib.rs"), "foo").unwrap();
+    add_and_commit(&mut repo, "feat: foo").unwrap();
+    fs::write(td.path().join("crates/bar/lib.rs"), "bar").unwrap();
+    add_and_commit(&mut repo, "feat: bar").unwrap();
+    fs::write(td.path().join("crates/foo/lib.rs"), "foo 2").unwrap();
+    fs::write(td.path().join("crates/bar/lib.rs"), "bar 2").unwrap();
+    add_and_commit(&mut repo, "fix: both").unwrap();
+    let filter = PathFilter {
+        include: ["crates/foo/".into()].into_iter().collect(),
+        ..Default::default()
+    };
+    let commits = commits_between_filtered(&repo, None, "HEAD", &filter, None).unwrap();
+    let summaries: Vec<_> = commits.iter().map(|c| c.summary.as_str()).collect();
+    assert_eq!(summaries, ["feat: foo", "fix: both"]);
+
+    // The root commit is filtered by the files it adds
+    let filter = PathFilter {
+        include: ["README.md".into()].into_iter().collect(),
+        ..Default::default()
+    };
+    let commits = commits_between_filtered(&repo, None, "HEAD", &filter, None).unwrap();
+    let summaries: Vec<_> = commits.iter().map(|c| c.summary.as_str()).collect();
+    assert_eq!(summaries, ["chore: init"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn signed_tag_carries_signature() {
+    use std::os::unix::fs::PermissionsExt;
+    let (td, mut repo) = init_repo();
+    fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    // Stand-in for gpg that checks the key and emits a fixed armored signature
+    let gpg = td.path().join("fake-gpg.sh");
+    fs::write(
+        &gpg,
+        "#!/bin/sh\n[ \"$3\" = \"TESTKEY\" ] || exit 2\ncat >/dev/null\n\
+         printf -- '-----BEGIN PGP SIGNATURE-----\\n\\nZHVtbXk=\\n-----END PGP SIGNATURE-----\\n'\n",
+    )
+    .unwrap();
+    fs::set_permissions(&gpg, fs::Permissions::from_mode(0o755)).unwrap();
+    let mut config = repo.config_snapshot_mut();
+    config.set_raw_value(&"user.signingkey", "TESTKEY").unwrap();
+    config
+        .set_raw_value(&"gpg.program", gpg.to_str().unwrap())
+        .unwrap();
+    config.commit().unwrap();
+
+    let id = create_signed_tag(&mut repo, "v1.0.0", "v1.0.0").unwrap();
+    {
+        let tag = repo.find_object(id).unwrap().into_tag();
+        let decoded = tag.decode().unwrap();
+        assert_eq!(decoded.name, "v1.0.0");
+        let signature = decoded.pgp_signature.unwrap();
+        assert!(signature.starts_with(b"-----BEGIN PGP SIGNATURE-----"));
+    }
+    assert_eq!(last_tag(&repo).unwrap().as_deref(), Some("v1.0.0"));
+
+    // A failing signer leaves no tag behind
+    let mut config = repo.config_snapshot_mut();
+    config.set_raw_value(&"user.signingkey", "OTHER").unwrap();
+    config.commit().unwrap();
+    let err = create_signed_tag(&mut repo, "v1.0.1", "v1.0.1").unwrap_err();
+    assert!(err.to_string().contains("failed to sign tag"), "{err}");
+    assert!(repo.try_find_reference("v1.0.1").unwrap().is_none());
+}
+
+#[test]
+fn max_reachable_tag_ignores_unmerged_branch() {
+    let (td, mut repo) = init_repo();
+    fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    create_tag(&mut repo, "v1.0.0", "v1.0.0", true).unwrap();
+    fs::write(td.path().join("b.txt"), "2").unwrap();
+    let base = add_and_commit(&mut repo, "feat: two").unwrap();
+    create_tag(&mut repo, "v1.1.0", "v1.1.0", true).unwrap();
+    // Release branch commit tagged higher, then abandoned
+    fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(&mut repo, "feat: three").unwrap();
+    create_tag(&mut repo, "v2.0.0", "v2.0.0", true).unwrap();
+    let branch = repo.head_name().unwrap().unwrap();
+    repo.reference(
+        branch,
+        base,
+        gix::refs::transaction::PreviousValue::Any,
+        "reset to trunk",
+    )
+    .unwrap();
+    fs::write(td.path().join("d.txt"), "4").unwrap();
+    add_and_commit(&mut repo, "fix: four").unwrap();
+
+    assert_eq!(last_tag(&repo).unwrap().as_deref(), Some("v2.0.0"));
+    assert_eq!(
+        max_reachable_semver_tag(&repo, "HEAD").unwrap().as_deref(),
+        Some("v1.1.0")
+    );
+}
+
+#[test]
+fn current_ref_detached_on_annotated_tag() {
+    let (td, mut repo) = init_repo();
+    fs::write(td.path().join("a.txt"), "1").unwrap();
+    let tagged = add_and_commit(&mut repo, "feat: one").unwrap();
+    create_tag(&mut repo, "v1.0.0", "release v1.0.0", true).unwrap();
+    fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "feat: two").unwrap();
+    // Detach HEAD at the annotated tag's commit
+    repo.reference(
+        "HEAD",
+        tagged,
+        gix::refs::transaction::PreviousValue::Any,
+        "checkout v1.0.0",
+    )
+    .unwrap();
+    assert!(repo.head().unwrap().is_detached());
+    assert_eq!(current_ref(&repo).unwrap().as_deref(), Some("v1.0.0"));
+}
+
 /// Test dirty detection with untracked files in the repository.
 #[test]
 fn dirty_detection_with_untracked() {
@@ -12,7 +12,7 @@ async fn test_get_username_no_token() {
 async fn test_sync_release_no_token_fallback() {
     let repo = Repository::parse("https://github.com/owner/repo.git").unwrap();
 
-    let result = sync_release(&repo, None, "v1.0.0", "Release body", None).await;
+    let result = sync_release(&repo, None, "v1.0.0", "Release body", false, None, None).await;
     assert!(result.is_ok());
     let info = result.unwrap();
     assert!(info.skipped);
@@ -25,7 +25,16 @@ async fn test_sync_release_no_token_fallback() {
 async fn test_sync_release_non_github_error() {
     let repo = Repository::parse("https://gitlab.com/owner/repo.git").unwrap();
 
-    let result = sync_release(&repo, Some("token"), "v1.0.0", "Release body", None).await;
+    let result = sync_release(
+        &repo,
+        Some("token"),
+        "v1.0.0",
+        "Release body",
+        false,
+        None,
+        None,
+    )
+    .await;
     assert!(result.is_err());
     match result.unwrap_err() {
         GithubError::NotGithub => (),
@@ -37,7 +46,16 @@ async fn test_sync_release_non_github_error() {
 async fn test_sync_release_bitbucket_error() {
     let repo = Repository::parse("https://bitbucket.org/owner/repo.git").unwrap();
 
-    let result = sync_release(&repo, Some("token"), "v1.0.0", "Release body", None).await;
+    let result = sync_release(
+        &repo,
+        Some("token"),
+        "v1.0.0",
+        "Release body",
+        false,
+        None,
+        None,
+    )
+    .await;
     assert!(result.is_err());
 }
 
@@ -86,6 +104,8 @@ async fn test_sync_release_with_custom_api_base() {
         Some("fake_token"),
         "v1.0.0",
         "Release body",
+        false,
+        None,
         Some("https://api.custom.com"),
     )
     .await;
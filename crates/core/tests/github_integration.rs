@@ -1,4 +1,6 @@
-use novalyn_core::github::{GithubError, get_username_from_email, sync_release};
+use novalyn_core::github::{
+    GithubError, fetch_pr_labels, get_pr_labels, get_username_from_email, sync_release,
+};
 use novalyn_core::repository::{Provider, Repository};
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -41,6 +43,64 @@ async fn test_get_username_from_email_success() {
     assert_eq!(result, Some("@testuser".into()));
 }
 
+/// Rate-limited responses are retried after the time the headers ask for.
+#[tokio::test]
+async fn test_rate_limited_requests_are_retried() {
+    wiremock_helpers::setup();
+    let mock_server = MockServer::start().await;
+
+    // Secondary rate limit, then an exhausted primary limit that already reset
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("X-RateLimit-Remaining", "0")
+                .insert_header("X-RateLimit-Reset", "0"),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{"login": "patient", "email": "p@example.com"}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = get_username_from_email("p@example.com", Some("t"), Some(&mock_server.uri()))
+        .await
+        .unwrap();
+    assert_eq!(result, Some("@patient".into()));
+}
+
+/// A plain 403 (no rate-limit headers) is not retried.
+#[tokio::test]
+async fn test_forbidden_without_rate_limit_not_retried() {
+    wiremock_helpers::setup();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = get_username_from_email("p@example.com", Some("t"), Some(&mock_server.uri()))
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+}
+
 /// Test that no username is returned when email is not found on GitHub.
 #[tokio::test]
 async fn test_get_username_from_email_not_found() {
@@ -85,7 +145,16 @@ async fn test_sync_release_not_github() {
         original: "https://gitlab.com/test/repo".into(),
     };
 
-    let result = sync_release(&repo, Some("token"), "v1.0.0", "Release notes", None).await;
+    let result = sync_release(
+        &repo,
+        Some("token"),
+        "v1.0.0",
+        "Release notes",
+        false,
+        None,
+        None,
+    )
+    .await;
 
     assert!(matches!(result, Err(GithubError::NotGithub)));
 }
@@ -125,6 +194,8 @@ async fn test_sync_release_create_new() {
         Some("test_token"),
         "v1.0.0",
         "Release notes",
+        false,
+        None,
         Some(&mock_server.uri()),
     )
     .await
@@ -175,6 +246,8 @@ async fn test_sync_release_update_existing() {
         Some("test_token"),
         "v1.0.0",
         "Updated release notes",
+        false,
+        None,
         Some(&mock_server.uri()),
     )
     .await
@@ -184,3 +257,124 @@ async fn test_sync_release_update_existing() {
     assert!(result.updated);
     assert!(!result.skipped);
 }
+
+/// Test fetching pull request labels, deduplicating repeated PR numbers.
+#[tokio::test]
+async fn test_fetch_pr_labels() {
+    wiremock_helpers::setup();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "number": 42,
+            "labels": [{"name": "enhancement"}, {"name": "ui"}]
+        })))
+        // one direct lookup plus one deduplicated batch lookup
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls/7"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let repo = Repository {
+        provider: Provider::GitHub,
+        host: "github.com".into(),
+        owner: "test".into(),
+        name: "repo".into(),
+        original: "https://github.com/test/repo".into(),
+    };
+
+    let single = get_pr_labels(&repo, 42, "test_token", Some(&mock_server.uri()))
+        .await
+        .unwrap();
+    assert_eq!(single.as_slice(), ["enhancement", "ui"]);
+
+    let labels = fetch_pr_labels(&repo, [42, 42, 7], "test_token", Some(&mock_server.uri())).await;
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[&42].as_slice(), ["enhancement", "ui"]);
+}
+
+/// Shared emails are looked up once, and hits and misses are served from the
+/// on-disk cache on the next run.
+#[tokio::test]
+async fn test_handle_lookups_deduplicated_and_cached() {
+    use ecow::{EcoString, EcoVec};
+    use novalyn_core::authors::{Author, Authors, DEFAULT_HANDLE_CACHE_TTL, HandleCache};
+    use wiremock::matchers::query_param;
+
+    wiremock_helpers::setup();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .and(query_param("q", "alice@example.com in:email"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{"login": "alice"}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .and(query_param("q", "nobody@example.com in:email"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"items": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let authors = || Authors {
+        list: EcoVec::from(vec![
+            Author {
+                name: EcoString::from("Alice"),
+                email: Some(EcoString::from("alice@example.com")),
+                domain: Some("example.com".into()),
+                first_time: false,
+            },
+            Author {
+                name: EcoString::from("Alice Work"),
+                email: Some(EcoString::from("Alice@Example.com")),
+                domain: Some("example.com".into()),
+                first_time: false,
+            },
+            Author {
+                name: EcoString::from("Nobody"),
+                email: Some(EcoString::from("nobody@example.com")),
+                domain: Some("example.com".into()),
+                first_time: false,
+            },
+        ]),
+        suppressed: false,
+    };
+    let names = |a: &Authors| a.list.iter().map(|a| a.name.clone()).collect::<Vec<_>>();
+    let expected = ["@alice", "@alice", "Nobody"];
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("novalyn").join("github-handles.json");
+    let api = mock_server.uri();
+
+    let mut cache = HandleCache::load(&path, DEFAULT_HANDLE_CACHE_TTL).await;
+    let mut first = authors();
+    first
+        .resolve_github_handles("test_token", Some(&api), Some(&mut cache))
+        .await
+        .unwrap();
+    assert_eq!(names(&first), expected);
+    cache.save().await.unwrap();
+
+    let mut cache = HandleCache::load(&path, DEFAULT_HANDLE_CACHE_TTL).await;
+    assert_eq!(cache.get("nobody@example.com"), Some(None));
+    let mut second = authors();
+    second
+        .resolve_github_handles("test_token", Some(&api), Some(&mut cache))
+        .await
+        .unwrap();
+    assert_eq!(names(&second), expected);
+
+    // Expired entries are looked up again
+    let stale = HandleCache::load(&path, std::time::Duration::ZERO).await;
+    assert_eq!(stale.get("alice@example.com"), None);
+}
@@ -19,7 +19,7 @@ mod wiremock_helpers {
 async fn github_sync_fallback_without_token() {
     // Use a GitHub-like repo struct
     let repo = Repository::parse("https://github.com/owner/repo.git").unwrap();
-    let info = sync_release(&repo, None, "v0.1.0", "Body", None)
+    let info = sync_release(&repo, None, "v0.1.0", "Body", false, None, None)
         .await
         .unwrap();
     assert!(info.skipped);
@@ -30,7 +30,7 @@ async fn github_sync_fallback_without_token() {
 async fn github_sync_constructs_correct_manual_url() {
     // Test that manual URL is correctly constructed for various repo formats
     let repo = Repository::parse("https://github.com/test/repo.git").unwrap();
-    let info = sync_release(&repo, None, "v1.2.3", "Release body", None)
+    let info = sync_release(&repo, None, "v1.2.3", "Release body", false, None, None)
         .await
         .unwrap();
 
@@ -45,7 +45,7 @@ async fn github_sync_constructs_correct_manual_url() {
 async fn github_sync_non_github_repo_error() {
     // Test that non-GitHub repos are rejected
     let repo = Repository::parse("https://gitlab.com/test/repo.git").unwrap();
-    let result = sync_release(&repo, Some("token"), "v1.0.0", "Body", None).await;
+    let result = sync_release(&repo, Some("token"), "v1.0.0", "Body", false, None, None).await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -80,6 +80,8 @@ async fn github_sync_create_release_with_wiremock() {
         Some("test-token"),
         "v1.0.0",
         "Release body",
+        false,
+        None,
         Some(&mock_server.uri()),
     )
     .await
@@ -120,6 +122,8 @@ async fn github_sync_update_existing_release_with_wiremock() {
         Some("test-token"),
         "v1.0.0",
         "Updated body",
+        false,
+        None,
         Some(&mock_server.uri()),
     )
     .await
@@ -129,3 +133,211 @@ async fn github_sync_update_existing_release_with_wiremock() {
     assert!(info.updated);
     assert!(!info.skipped);
 }
+
+#[tokio::test]
+async fn github_sync_update_sends_draft_and_detected_prerelease() {
+    use wiremock::matchers::body_partial_json;
+
+    wiremock_helpers::setup();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/releases/tags/v1.0.0-rc.1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 7,
+            "html_url": "https://github.com/test/repo/releases/tag/v1.0.0-rc.1"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test/repo/releases/7"))
+        .and(body_partial_json(serde_json::json!({
+            "draft": true,
+            "prerelease": true,
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let repo = Repository::parse("https://github.com/test/repo.git").unwrap();
+    let info = sync_release(
+        &repo,
+        Some("test-token"),
+        "v1.0.0-rc.1",
+        "Body",
+        true,
+        None,
+        Some(&mock_server.uri()),
+    )
+    .await
+    .unwrap();
+    assert!(info.updated);
+}
+
+#[test]
+fn prerelease_detected_from_tag_unless_overridden() {
+    use novalyn_core::github::{ReleasePayload, tag_is_prerelease};
+
+    assert!(tag_is_prerelease("v1.0.0-rc.1"));
+    assert!(tag_is_prerelease("my-crate-v0.2.0-beta"));
+    assert!(!tag_is_prerelease("v1.0.0"));
+    assert!(!tag_is_prerelease("nightly"));
+    let payload = ReleasePayload {
+        tag: "v2.0.0-alpha.1",
+        name: "v2.0.0-alpha.1",
+        body: "",
+        draft: false,
+        prerelease: Some(false),
+    };
+    assert!(!payload.create_request().prerelease);
+}
+
+#[tokio::test]
+async fn github_sync_uses_templated_release_name() {
+    use novalyn_core::config::ResolvedConfig;
+    use novalyn_core::github::{ReleasePayload, sync_release_with};
+    use wiremock::matchers::body_partial_json;
+
+    wiremock_helpers::setup();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/releases/tags/v1.1.0"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    // Only matches when the templated title is sent
+    Mock::given(method("POST"))
+        .and(path("/repos/test/repo/releases"))
+        .and(body_partial_json(serde_json::json!({
+            "tag_name": "v1.1.0",
+            "name": "Release 1.1.0 (since 1.0.0) 2024-05-01",
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "html_url": "https://github.com/test/repo/releases/tag/v1.1.0"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let cfg = ResolvedConfig {
+        release_name_template: Some(
+            "Release {{newVersion}} (since {{previousVersion}}) {{date}}".into(),
+        ),
+        ..Default::default()
+    };
+    let name = cfg.release_name(
+        "v1.1.0",
+        &semver::Version::new(1, 1, 0),
+        Some(&semver::Version::new(1, 0, 0)),
+        "2024-05-01",
+    );
+    let untemplated = ResolvedConfig::default();
+    assert_eq!(
+        untemplated.release_name("v1.1.0", &semver::Version::new(1, 1, 0), None, "2024-05-01"),
+        "v1.1.0"
+    );
+    let repo = Repository::parse("https://github.com/test/repo.git").unwrap();
+    let payload = ReleasePayload {
+        tag: "v1.1.0",
+        name: &name,
+        body: "Notes",
+        draft: false,
+        prerelease: None,
+    };
+    let info = sync_release_with(
+        &repo,
+        Some("test-token"),
+        &payload,
+        Some(&mock_server.uri()),
+    )
+    .await
+    .unwrap();
+
+    assert!(info.created);
+}
+
+/// Point `origin` of a fresh repository at `remote`.
+fn repo_with_remote(remote: &str) -> tempfile::TempDir {
+    let td = tempfile::TempDir::new().unwrap();
+    novalyn_core::git::init_repo(td.path()).unwrap();
+    let mut git_config = std::fs::OpenOptions::new()
+        .append(true)
+        .open(td.path().join(".git/config"))
+        .unwrap();
+    std::io::Write::write_all(
+        &mut git_config,
+        format!("[remote \"origin\"]\n\turl = {remote}\n").as_bytes(),
+    )
+    .unwrap();
+    td
+}
+
+#[tokio::test]
+async fn github_enterprise_api_url_from_config() {
+    use novalyn_core::config::{LoadOptions, load_config};
+    use novalyn_core::github::{ReleasePayload, sync_release_with};
+    use novalyn_core::repository::Provider;
+
+    wiremock_helpers::setup();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/repos/o/r/releases/tags/v1.0.0"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v3/repos/o/r/releases"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "html_url": "https://ghe.example/o/r/releases/tag/v1.0.0"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Remote on the API host, which isn't recognizable as GitHub by name
+    let td = repo_with_remote(&format!("{}/o/r.git", mock_server.uri()));
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        format!("[github]\napi_url = \"{}/api/v3/\"\n", mock_server.uri()),
+    )
+    .unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(cfg.github_api_url, format!("{}/api/v3", mock_server.uri()));
+    let repo = cfg.repo.clone().unwrap();
+    assert_eq!(repo.provider, Provider::GitHub);
+
+    let payload = ReleasePayload {
+        tag: "v1.0.0",
+        name: "v1.0.0",
+        body: "Notes",
+        draft: false,
+        prerelease: None,
+    };
+    let info = sync_release_with(
+        &repo,
+        Some("test-token"),
+        &payload,
+        Some(&cfg.github_api_url),
+    )
+    .await
+    .unwrap();
+    assert!(info.created);
+
+    // Without an explicit URL, a github.<domain> remote uses its /api/v3 endpoint
+    let td = repo_with_remote("https://github.mycorp.com/o/r.git");
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(cfg.github_api_url, "https://github.mycorp.com/api/v3");
+    assert_eq!(cfg.repo.unwrap().provider, Provider::GitHub);
+}
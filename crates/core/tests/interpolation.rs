@@ -34,3 +34,44 @@ fn interpolation_date_format() {
     let out = interpolate("{{date}}", &prev, &new, &date);
     assert_eq!(out, "2025-01-05");
 }
+
+#[test]
+fn outro_interpolates_env_tokens() {
+    use novalyn_core::config::ResolvedConfig;
+    use novalyn_core::git::RawCommit;
+    use novalyn_core::parse::parse_and_classify;
+    use novalyn_core::render::{RenderContext, render_release_block};
+
+    unsafe {
+        std::env::set_var("NOVALYN_TEST_BUILD_URL", "https://ci.example/run/42");
+    }
+    let cfg = ResolvedConfig {
+        outro: Some(
+            "Build: {{env.NOVALYN_TEST_BUILD_URL}} for v{{newVersion}}{{env.NOVALYN_TEST_UNSET}}"
+                .into(),
+        ),
+        ..Default::default()
+    };
+    let raw = RawCommit {
+        id: "1".into(),
+        short_id: "1".into(),
+        summary: "feat: add".into(),
+        ..Default::default()
+    };
+    let commits = parse_and_classify(vec![raw].into(), &cfg);
+    let txt = render_release_block(&RenderContext {
+        version: &Version::parse("1.1.0").unwrap(),
+        previous_version: None,
+        commits: &commits,
+        authors: None,
+        repo: None,
+        cfg: &cfg,
+        previous_tag: None,
+        first_commit: None,
+        current_ref: "HEAD",
+    });
+    assert!(
+        txt.ends_with("* ✨: add\n\nBuild: https://ci.example/run/42 for v1.1.0\n"),
+        "{txt}"
+    );
+}
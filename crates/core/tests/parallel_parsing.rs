@@ -83,3 +83,44 @@ fn parallel_threshold_respected() {
         env::remove_var("NOVALYN_PARALLEL_THRESHOLD");
     }
 }
+
+#[test]
+fn chunked_parallel_matches_sequential_for_any_chunk_size() {
+    let td = TempDir::new().unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    // Mixed input so some commits are filtered out
+    let commits: Vec<RawCommit> = create_test_commits(40)
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut c)| {
+            if i % 3 == 0 {
+                c.summary = format!("chore(deps): bump {i}").into();
+            } else if i % 5 == 0 {
+                c.summary = format!("fix(core)!: change {i}").into();
+            }
+            c
+        })
+        .collect();
+
+    // Small inputs take the sequential path regardless of chunking
+    let reference = parse_and_classify(commits.clone().into(), &cfg);
+    let summarize = |parsed: &[novalyn_core::parse::ParsedCommit]| {
+        parsed
+            .iter()
+            .map(|p| (p.index, p.raw.id.clone(), p.r#type.clone(), p.breaking))
+            .collect::<Vec<_>>()
+    };
+    assert!(reference.len() < commits.len());
+    for chunk_size in [0, 1, 7, 16, 64] {
+        let chunked = novalyn_core::parse::parse_and_classify_chunked(&commits, &cfg, chunk_size);
+        assert_eq!(
+            summarize(&chunked),
+            summarize(&reference),
+            "chunk size {chunk_size}"
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use assert_fs::TempDir;
-use novalyn_core::config::{LoadOptions, load_config};
+use novalyn_core::config::{LoadOptions, OnEmptyDescription, load_config};
 use novalyn_core::git::RawCommit;
 use novalyn_core::parse::{ParsedCommit, parse_and_classify};
 
@@ -51,3 +51,99 @@ impl Summ for ParsedCommit {
         &self.raw.summary
     }
 }
+
+#[test]
+fn empty_description_modes() {
+    let td = TempDir::new().unwrap();
+    let mut cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = || vec![mk_commit("feat: ", ""), mk_commit("fix: real", "")];
+
+    // Default: fall back to the raw summary
+    assert_eq!(cfg.on_empty_description, OnEmptyDescription::Summary);
+    let parsed = parse_and_classify(commits().into(), &cfg);
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].description, "feat:");
+
+    cfg.on_empty_description = OnEmptyDescription::Keep;
+    let parsed = parse_and_classify(commits().into(), &cfg);
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].description, "");
+
+    cfg.on_empty_description = OnEmptyDescription::Skip;
+    let parsed = parse_and_classify(commits().into(), &cfg);
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].description, "real");
+}
+
+#[test]
+fn migration_footer_captured() {
+    let td = TempDir::new().unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = vec![mk_commit(
+        "feat!: new config format",
+        "Details.\n\nMigration: Rename `a` to `b`.\n  Then run `tool migrate`.\nRefs: #12",
+    )];
+    let parsed = parse_and_classify(commits.into(), &cfg);
+    assert_eq!(
+        parsed[0].migration.as_deref(),
+        Some("Rename `a` to `b`.\nThen run `tool migrate`.")
+    );
+}
+
+#[test]
+fn type_resolution_precedence() {
+    let td = TempDir::new().unwrap();
+    let mut cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    cfg.type_aliases.insert("feature".into(), "feat".into());
+    cfg.type_aliases.insert("fixed".into(), "fix".into());
+    cfg.keyword_types.insert("fixed".into(), "perf".into());
+    cfg.keyword_types.insert("sped".into(), "perf".into());
+    let commits = vec![
+        // Footer beats the alias
+        mk_commit("feature: add search", "Changelog-Category: Docs"),
+        // Alias beats the raw type
+        mk_commit("feature: add filter", ""),
+        // Alias beats the keyword for a non-conventional summary
+        mk_commit("Fixed crash on start", ""),
+        // Keyword alone
+        mk_commit("Sped up startup", ""),
+        // Nothing applies
+        mk_commit("refactor: tidy", ""),
+    ];
+    let types: Vec<_> = parse_and_classify(commits.into(), &cfg)
+        .iter()
+        .map(|c| (c.description.to_string(), c.r#type.to_string()))
+        .collect();
+    assert_eq!(
+        types,
+        [
+            ("add search", "docs"),
+            ("add filter", "feat"),
+            ("Fixed crash on start", "fix"),
+            ("Sped up startup", "perf"),
+            ("tidy", "refactor"),
+        ]
+        .map(|(d, t)| (d.to_string(), t.to_string()))
+    );
+
+    // An empty footer key turns the override off
+    cfg.category_footer = "".into();
+    let parsed = parse_and_classify(
+        vec![mk_commit("feature: add search", "Changelog-Category: docs")].into(),
+        &cfg,
+    );
+    assert_eq!(parsed[0].r#type, "feat");
+    assert_eq!(parsed[0].type_cfg.as_ref().unwrap().key, "feat");
+}
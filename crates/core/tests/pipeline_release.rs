@@ -1,4 +1,6 @@
+use novalyn_core::config::PreviousTagStrategy;
 use novalyn_core::git::add_and_commit;
+use novalyn_core::parse::BumpKind;
 use novalyn_core::pipeline::{ExitCode, ReleaseOptions, run_release};
 use tempfile::TempDir;
 
@@ -29,6 +31,7 @@ fn dry_run_leaves_changelog_untouched() {
         yes: true,
         github_alias: false,
         github_token: None,
+        ..Default::default()
     })
     .unwrap();
     assert_eq!(outcome.exit as i32, ExitCode::NoChange as i32); // dry run reports no change (wrote=false)
@@ -56,6 +59,7 @@ fn exit_code_no_change() {
         yes: true,
         github_alias: false,
         github_token: None,
+        ..Default::default()
     })
     .unwrap();
     assert!(outcome1.wrote);
@@ -75,9 +79,637 @@ fn exit_code_no_change() {
         yes: true,
         github_alias: false,
         github_token: None,
+        ..Default::default()
     })
     .unwrap();
     assert!(!outcome2.wrote);
     assert_eq!(outcome2.version, outcome1.version); // unchanged version
     assert_eq!(outcome2.exit as i32, ExitCode::NoChange as i32);
 }
+
+/// Test that strict mode leaves out and counts non-conventional commits.
+#[test]
+fn strict_conventional_excludes_malformed_commits() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "Update stuff").unwrap();
+    std::fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(&mut repo, "fix(core) missing colon").unwrap();
+    let outcome = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        strict_conventional: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(outcome.commit_count, 1);
+    assert_eq!(outcome.non_conventional, 2);
+    assert!(!outcome.block.contains("Update stuff"));
+    assert!(!outcome.block.contains("missing colon"));
+}
+
+/// Test that the classification report lists commits and the version summary.
+#[test]
+fn report_lists_classification_and_summary() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "fix(core): one").unwrap();
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "feat!: two").unwrap();
+    std::fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(
+        &mut repo,
+        "perf: three\n\nBREAKING CHANGE: cache format changed",
+    )
+    .unwrap();
+    run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        report: Some("report.json".into()),
+        ..Default::default()
+    })
+    .unwrap();
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(td.path().join("report.json")).unwrap())
+            .unwrap();
+    assert_eq!(report["summary"]["previous_version"], "0.0.0");
+    assert_eq!(report["summary"]["version"], "0.1.0");
+    assert_eq!(report["summary"]["bump"], "minor"); // degraded on 0.x
+    assert_eq!(report["summary"]["commit_count"], 3);
+    let commits = report["commits"].as_array().unwrap();
+    assert_eq!(commits[0]["type"], "fix");
+    assert_eq!(commits[0]["scope"], "core");
+    assert_eq!(commits[0]["breaking"], false);
+    assert_eq!(commits[0]["breaking_source"], serde_json::Value::Null);
+    assert_eq!(commits[0]["impact"], "patch");
+    assert_eq!(commits[1]["type"], "feat");
+    assert_eq!(commits[1]["scope"], serde_json::Value::Null);
+    assert_eq!(commits[1]["breaking"], true);
+    assert_eq!(commits[1]["breaking_source"], "marker");
+    assert_eq!(commits[1]["impact"], "major");
+    assert_eq!(commits[2]["breaking_source"], "footer");
+    assert_eq!(commits[2]["impact"], "major");
+}
+
+/// Test that a breaking commit of a disabled type drives the bump, is left
+/// out of its type section and is listed once under breaking changes.
+#[test]
+fn disabled_type_breaking_commit_drives_bump_once() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("novalyn.toml"), "[types]\nchore = false\n").unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "fix: one").unwrap();
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "chore!: drop node 16").unwrap();
+    std::fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(&mut repo, "chore: bump lockfile").unwrap();
+    let outcome = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        new_version: None,
+        report: Some("report.json".into()),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(outcome.bump, BumpKind::Minor); // major degraded on 0.x
+
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(td.path().join("report.json")).unwrap())
+            .unwrap();
+    let commits = report["commits"].as_array().unwrap();
+    assert_eq!(commits.len(), 2);
+    let chore = commits.iter().find(|c| c["type"] == "chore").unwrap();
+    assert_eq!(chore["impact"], "major");
+    assert_eq!(chore["drives_bump"], true);
+    assert_eq!(chore["section"], serde_json::Value::Null);
+    let fix = commits.iter().find(|c| c["type"] == "fix").unwrap();
+    assert_eq!(fix["drives_bump"], false);
+    assert_eq!(fix["section"], "fix");
+    assert_eq!(
+        report["summary"]["sections"],
+        serde_json::json!({ "fix": 1 })
+    );
+
+    let block = outcome.block.as_str();
+    assert_eq!(block.matches("drop node 16").count(), 1, "{block}");
+    assert!(
+        block.contains("### ⚠️ Breaking Changes\n* chore: drop node 16\n"),
+        "{block}"
+    );
+    assert!(!block.contains("Chores"), "{block}");
+    assert!(!block.contains("bump lockfile"), "{block}");
+}
+
+/// Test that tags created from `tag_format` are detected by the next release.
+#[test]
+fn tag_format_round_trips_through_release() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "tag_format = \"myproj/v{{newVersion}}-release\"\n",
+    )
+    .unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    let opts = ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    };
+    let first = run_release(opts.clone()).unwrap();
+    assert_eq!(first.version.to_string(), "0.0.1");
+    assert!(
+        repo.find_reference("refs/tags/myproj/v0.0.1-release")
+            .is_ok()
+    );
+
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "fix: two").unwrap();
+    let second = run_release(opts).unwrap();
+    assert_eq!(second.previous.unwrap().to_string(), "0.0.1");
+    assert_eq!(second.version.to_string(), "0.0.2");
+    assert_eq!(second.commit_count, 1);
+}
+
+#[test]
+fn banned_scope_fails_release() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "banned_scopes = [\"internal\"]\n",
+    )
+    .unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat(api): public").unwrap();
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "fix(internal): secret").unwrap();
+    let Err(err) = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    }) else {
+        panic!("release with a banned scope must fail");
+    };
+    let err = err
+        .downcast_ref::<novalyn_core::error::NovalynError>()
+        .expect("governance failure is a NovalynError");
+    let msg = err.to_string();
+    assert!(msg.contains("1 commit(s) use a banned scope"), "{msg}");
+    assert!(msg.contains("fix(internal): secret"), "{msg}");
+    assert!(!msg.contains("public"), "{msg}");
+    assert!(!td.path().join("CHANGELOG.md").exists());
+}
+
+#[test]
+fn banned_scope_checked_before_scope_map_and_filters() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "banned_scopes = [\"internal\"]\n\n[scope_map]\ninternal = \"\"\n",
+    )
+    .unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat(api, internal): both").unwrap();
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "fix(internal): mapped away").unwrap();
+    std::fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(&mut repo, "fix(internal): hidden [skip changelog]").unwrap();
+    std::fs::write(td.path().join("d.txt"), "4").unwrap();
+    add_and_commit(&mut repo, "feat(api): public").unwrap();
+    let Err(err) = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    }) else {
+        panic!("release with a banned scope must fail");
+    };
+    let msg = err.to_string();
+    assert!(msg.contains("3 commit(s) use a banned scope"), "{msg}");
+    assert!(msg.contains("feat(api, internal): both"), "{msg}");
+    assert!(msg.contains("fix(internal): mapped away"), "{msg}");
+    assert!(msg.contains("hidden [skip changelog]"), "{msg}");
+    assert!(!msg.contains("public"), "{msg}");
+}
+
+#[test]
+fn base_limits_range_to_branch_commits() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    let shared = add_and_commit(&mut repo, "feat: shared").unwrap();
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    let trunk = add_and_commit(&mut repo, "fix: trunk only").unwrap();
+    repo.reference(
+        "refs/heads/trunk",
+        trunk,
+        gix::refs::transaction::PreviousValue::Any,
+        "base branch",
+    )
+    .unwrap();
+    // Move the current branch back and diverge from trunk
+    let branch = repo.head_name().unwrap().unwrap();
+    repo.reference(
+        branch,
+        shared,
+        gix::refs::transaction::PreviousValue::Any,
+        "feature branch",
+    )
+    .unwrap();
+    std::fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(&mut repo, "feat: branch work").unwrap();
+
+    let outcome = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        base: Some("trunk".into()),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(outcome.commit_count, 1);
+    assert!(outcome.block.contains("branch work"));
+    assert!(!outcome.block.contains("shared"));
+    assert!(!outcome.block.contains("trunk only"));
+}
+
+#[test]
+fn config_new_version_used_unless_overridden() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("novalyn.toml"), "new_version = \"3.0.0\"\n").unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "fix: one").unwrap();
+    let opts = ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    };
+    let outcome = run_release(opts.clone()).unwrap();
+    assert_eq!(outcome.version.to_string(), "3.0.0");
+
+    let outcome = run_release(ReleaseOptions {
+        new_version: Some(semver::Version::new(4, 0, 0)),
+        ..opts
+    })
+    .unwrap();
+    assert_eq!(outcome.version.to_string(), "4.0.0");
+}
+
+#[test]
+fn conflicted_changelog_refused_unless_forced() {
+    let (td, mut repo) = init_repo();
+    let conflicted = "<<<<<<< HEAD\n## v0.0.1\nours\n=======\n## v0.0.1\ntheirs\n>>>>>>> other\n";
+    std::fs::write(td.path().join("CHANGELOG.md"), conflicted).unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    let opts = ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    };
+    let Err(err) = run_release(opts.clone()) else {
+        panic!("release onto a conflicted changelog must fail");
+    };
+    assert!(err.to_string().contains("merge conflict markers"), "{err}");
+    assert_eq!(
+        std::fs::read_to_string(td.path().join("CHANGELOG.md")).unwrap(),
+        conflicted
+    );
+
+    let outcome = run_release(ReleaseOptions {
+        force: true,
+        ..opts
+    })
+    .unwrap();
+    assert!(outcome.wrote);
+}
+
+#[test]
+fn prerelease_channel_tags_rc_versions() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    let opts = ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        prerelease: Some("rc".into()),
+        ..Default::default()
+    };
+    let first = run_release(opts.clone()).unwrap();
+    assert_eq!(first.version.to_string(), "0.0.1-rc.1");
+    assert!(repo.find_reference("refs/tags/v0.0.1-rc.1").is_ok());
+
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "fix: two").unwrap();
+    let second = run_release(opts.clone()).unwrap();
+    assert_eq!(second.version.to_string(), "0.0.1-rc.2");
+
+    std::fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(&mut repo, "fix: three").unwrap();
+    let stable = run_release(ReleaseOptions {
+        prerelease: None,
+        ..opts
+    })
+    .unwrap();
+    assert_eq!(stable.version.to_string(), "0.0.1");
+}
+
+#[test]
+fn default_strategy_uses_tag_reachable_from_branch() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    let base = add_and_commit(&mut repo, "feat: base").unwrap();
+    novalyn_core::git::create_tag(&mut repo, "v1.0.0", "v1.0.0", false).unwrap();
+    // A newer release tagged on another branch
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "feat: elsewhere").unwrap();
+    novalyn_core::git::create_tag(&mut repo, "v2.0.0", "v2.0.0", false).unwrap();
+    let branch = repo.head_name().unwrap().unwrap();
+    repo.reference(
+        branch,
+        base,
+        gix::refs::transaction::PreviousValue::Any,
+        "back to base",
+    )
+    .unwrap();
+    std::fs::write(td.path().join("c.txt"), "3").unwrap();
+    add_and_commit(&mut repo, "fix: branch fix").unwrap();
+
+    let opts = ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        default_previous_tag_strategy: Some(PreviousTagStrategy::SemverReachable),
+        ..Default::default()
+    };
+    let outcome = run_release(opts.clone()).unwrap();
+    assert_eq!(outcome.previous.unwrap().to_string(), "1.0.0");
+    assert_eq!(outcome.version.to_string(), "1.0.1");
+    assert_eq!(outcome.commit_count, 1);
+
+    // An explicit config strategy still wins over the command default
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "previous_tag_strategy = \"latest\"\n",
+    )
+    .unwrap();
+    let outcome = run_release(opts).unwrap();
+    assert_eq!(outcome.previous.unwrap().to_string(), "2.0.0");
+}
+
+#[test]
+fn outcome_reports_degraded_bump_for_zero_major() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    novalyn_core::git::create_tag(&mut repo, "v0.1.0", "v0.1.0", false).unwrap();
+    std::fs::write(td.path().join("b.txt"), "2").unwrap();
+    add_and_commit(&mut repo, "feat!: redo api").unwrap();
+    let outcome = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    })
+    .unwrap();
+    // A breaking change on 0.x bumps the minor version and is reported as such
+    assert_eq!(outcome.version.to_string(), "0.2.0");
+    assert_eq!(outcome.bump, BumpKind::Minor);
+}
+
+/// Authors absent from the history before the range are listed as new contributors.
+#[test]
+fn new_contributors_listed_when_absent_from_prior_history() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("novalyn.toml"), "new_contributors = true\n").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    novalyn_core::git::create_tag(&mut repo, "v0.1.0", "v0.1.0", true).unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(
+        &mut repo,
+        "fix: two\n\nCo-authored-by: Bob <bob@example.com>",
+    )
+    .unwrap();
+    let outcome = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        yes: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(
+        outcome
+            .block
+            .contains("### Contributors\n- Tester <tester@example.com>\n- Bob <bob@example.com>\n"),
+        "{}",
+        outcome.block
+    );
+    assert!(
+        outcome
+            .block
+            .ends_with("### New Contributors\n- Bob made their first contribution\n"),
+        "{}",
+        outcome.block
+    );
+}
+
+/// Without a release tag the Cargo.toml version is the previous version;
+/// a tag takes precedence, and the fallback can be switched off.
+#[test]
+fn previous_version_falls_back_to_manifest() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(
+        td.path().join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"1.4.0\"\n",
+    )
+    .unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    let preview = |opts: ReleaseOptions| {
+        let outcome = run_release(ReleaseOptions {
+            cwd: td.path().into(),
+            dry_run: true,
+            no_authors: true,
+            yes: true,
+            ..opts
+        })
+        .unwrap();
+        (
+            outcome.previous.unwrap().to_string(),
+            outcome.version.to_string(),
+        )
+    };
+
+    assert_eq!(
+        preview(Default::default()),
+        ("1.4.0".to_string(), "1.5.0".to_string())
+    );
+    assert_eq!(
+        preview(ReleaseOptions {
+            no_manifest_version: true,
+            ..Default::default()
+        }),
+        ("0.0.0".to_string(), "0.0.1".to_string())
+    );
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "manifest_version_fallback = false\n",
+    )
+    .unwrap();
+    assert_eq!(preview(Default::default()).0, "0.0.0");
+    std::fs::remove_file(td.path().join("novalyn.toml")).unwrap();
+
+    novalyn_core::git::create_tag(&mut repo, "v2.0.0", "v2.0.0", true).unwrap();
+    add_and_commit(&mut repo, "fix: two").unwrap();
+    assert_eq!(
+        preview(Default::default()),
+        ("2.0.0".to_string(), "2.0.1".to_string())
+    );
+}
+
+/// Commits that are all filtered out are no change, not a phantom patch release.
+#[test]
+fn all_filtered_commits_are_no_change() {
+    let (td, mut repo) = init_repo();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    let release = || {
+        run_release(ReleaseOptions {
+            cwd: td.path().into(),
+            no_authors: true,
+            yes: true,
+            no_manifest_version: true,
+            ..Default::default()
+        })
+        .unwrap()
+    };
+    let first = release();
+    assert_eq!(first.version.to_string(), "0.0.1");
+    let changelog = std::fs::read_to_string(&first.changelog_path).unwrap();
+
+    add_and_commit(&mut repo, "chore(deps): bump serde").unwrap();
+    add_and_commit(&mut repo, "chore(deps-dev): bump insta").unwrap();
+    let outcome = release();
+    assert_eq!(outcome.exit as i32, ExitCode::NoChange as i32);
+    assert!(!outcome.wrote);
+    assert_eq!(outcome.version.to_string(), "0.0.1");
+    assert_eq!(outcome.bump, BumpKind::None);
+    assert_eq!(outcome.commit_count, 0);
+    assert_eq!(
+        std::fs::read_to_string(&outcome.changelog_path).unwrap(),
+        changelog
+    );
+    assert_eq!(
+        novalyn_core::git::last_tag(&repo).unwrap().as_deref(),
+        Some("v0.0.1")
+    );
+}
+
+/// With `hide_reverted`, a commit and its revert in the same range both drop out.
+#[test]
+fn hide_reverted_drops_commit_and_revert() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(td.path().join("novalyn.toml"), "hide_reverted = true\n").unwrap();
+    add_and_commit(&mut repo, "fix: keep me").unwrap();
+    let feat = add_and_commit(&mut repo, "feat: short-lived").unwrap();
+    add_and_commit(
+        &mut repo,
+        &format!("revert: feat: short-lived\n\nThis reverts commit {feat}."),
+    )
+    .unwrap();
+    let outcome = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        dry_run: true,
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(outcome.block.contains("keep me"), "{}", outcome.block);
+    assert!(!outcome.block.contains("short-lived"), "{}", outcome.block);
+}
+
+#[cfg(unix)]
+#[test]
+fn release_hooks_run_around_the_release() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "pre_release_hooks = [\"echo {{previousVersion}}-{{newVersion}} > pre.txt\"]\n\
+         post_release_hooks = [\"ls CHANGELOG.md > post.txt\", \"exit 1\"]\n",
+    )
+    .unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    let opts = ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        no_tag: true,
+        run_hooks: true,
+        ..Default::default()
+    };
+
+    let dry = run_release(ReleaseOptions {
+        dry_run: true,
+        ..opts.clone()
+    })
+    .unwrap();
+    assert_eq!(
+        dry.skipped_hooks.as_slice(),
+        [
+            "echo 0.0.0-0.0.1 > pre.txt",
+            "ls CHANGELOG.md > post.txt",
+            "exit 1"
+        ]
+    );
+    assert!(!td.path().join("pre.txt").exists());
+
+    // A failing post-release hook only warns
+    let outcome = run_release(opts).unwrap();
+    assert!(outcome.wrote);
+    assert!(outcome.skipped_hooks.is_empty());
+    let pre = std::fs::read_to_string(td.path().join("pre.txt")).unwrap();
+    assert_eq!(pre.trim(), "0.0.0-0.0.1");
+    let post = std::fs::read_to_string(td.path().join("post.txt")).unwrap();
+    assert_eq!(post.trim(), "CHANGELOG.md");
+}
+
+#[cfg(unix)]
+#[test]
+fn failing_pre_release_hook_aborts() {
+    let (td, mut repo) = init_repo();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "pre_release_hooks = [\"exit 3\"]\n",
+    )
+    .unwrap();
+    std::fs::write(td.path().join("a.txt"), "1").unwrap();
+    add_and_commit(&mut repo, "feat: one").unwrap();
+    let Err(err) = run_release(ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        run_hooks: true,
+        ..Default::default()
+    }) else {
+        panic!("a failing pre-release hook must abort the release");
+    };
+    let msg = err.to_string();
+    assert!(msg.contains("pre-release hook `exit 3` failed"), "{msg}");
+    assert!(!td.path().join("CHANGELOG.md").exists());
+}
@@ -55,9 +55,11 @@ proptest! {
         let config = TypeConfigResolved {
             key: key.clone().into(),
             title: title.into(),
+            singular_title: None,
             emoji: emoji.into(),
             semver,
             enabled,
+            link_refs: true,
         };
 
         assert_eq!(config.key.as_str(), key);
@@ -227,18 +229,22 @@ mod config_specifics {
         let enabled = TypeConfigResolved {
             key: "test".into(),
             title: "Test".into(),
+            singular_title: None,
             emoji: "🧪".into(),
             semver: SemverImpact::None,
             enabled: true,
+            link_refs: true,
         };
         assert!(enabled.enabled);
 
         let disabled = TypeConfigResolved {
             key: "test".into(),
             title: "Test".into(),
+            singular_title: None,
             emoji: "🧪".into(),
             semver: SemverImpact::None,
             enabled: false,
+            link_refs: true,
         };
         assert!(!disabled.enabled);
     }
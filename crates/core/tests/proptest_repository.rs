@@ -131,8 +131,8 @@ proptest! {
             "https://host.com/owner",
             "git@host.com:",
             "git@host.com:owner",
-            // URL with extra path segments (not currently supported)
-            "https://gitlab.com/owner/group/project",
+            // Extra path segments are only meaningful for GitLab subgroups
+            "https://github.com/owner/group/project",
         ])
     ) {
         let result = Repository::parse(invalid);
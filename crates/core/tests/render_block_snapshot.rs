@@ -16,6 +16,7 @@ fn cfg() -> ResolvedConfig {
         cwd: ".".into(),
         source_file: None,
         repo: None,
+        ..Default::default()
     }
 }
 
@@ -41,6 +42,7 @@ fn mk(idx: usize, t: &str, desc: &str) -> ParsedCommit {
         co_authors: vec![].into(),
         type_cfg: None,
         index: idx,
+        ..Default::default()
     }
 }
 
@@ -57,6 +59,7 @@ fn render_block_snapshot() {
         repo: None,
         cfg: &cfg,
         previous_tag: Some("v1.1.0"),
+        first_commit: None,
         current_ref: "HEAD",
     };
     let txt = render_release_block(&rc);
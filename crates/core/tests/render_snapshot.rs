@@ -15,6 +15,7 @@ fn dummy_cfg() -> ResolvedConfig {
         cwd: std::path::PathBuf::from("."),
         source_file: None,
         repo: None,
+        ..Default::default()
     }
 }
 
@@ -39,6 +40,7 @@ fn mk(idx: usize, t: &str, desc: &str) -> ParsedCommit {
         co_authors: vec![].into(),
         type_cfg: None,
         index: idx,
+        ..Default::default()
     }
 }
 
@@ -59,6 +61,7 @@ fn deterministic_ordering() {
         repo: None,
         cfg: &cfg,
         previous_tag: None,
+        first_commit: None,
         current_ref: "HEAD",
     };
     let txt = render_release_block(&rc);
@@ -78,6 +81,7 @@ fn deterministic_ordering() {
         repo: None,
         cfg: &cfg,
         previous_tag: None,
+        first_commit: None,
         current_ref: "HEAD",
     };
     let txt2 = render_release_block(&rc2);
@@ -88,3 +92,36 @@ fn deterministic_ordering() {
         .collect();
     assert_eq!(lines2, vec!["* ✨: first", "* ✨: second", "* ✨: third"]);
 }
+
+#[test]
+fn untitled_custom_type_gets_title_cased_heading() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "[types.hotfix]\nsemver = \"patch\"\n\n[types.security-fix]\nemoji = \"🔒\"\n",
+    )
+    .unwrap();
+    let cfg = novalyn_core::config::load_config(novalyn_core::config::LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let mut hotfix = mk(0, "hotfix", "patch prod");
+    hotfix.type_cfg = cfg.types.iter().find(|t| t.key == "hotfix").cloned();
+    let commits = vec![hotfix, mk(1, "security-fix", "escape input")];
+    let rc = RenderContext {
+        version: &semver::Version::parse("1.0.1").unwrap(),
+        previous_version: None,
+        commits: &commits,
+        authors: None,
+        repo: None,
+        cfg: &cfg,
+        previous_tag: None,
+        first_commit: None,
+        current_ref: "HEAD",
+    };
+    let txt = render_release_block(&rc);
+    assert!(txt.contains("\n### Hotfix\n* patch prod\n"), "{txt}");
+    assert!(txt.contains("\n### 🔒 Security Fix\n"), "{txt}");
+    assert!(!txt.contains("### hotfix"), "{txt}");
+}
@@ -54,6 +54,17 @@ fn test_format_reference_pr_github() {
     );
 }
 
+#[test]
+fn test_format_reference_merge_request_gitlab() {
+    let repo = make_repo(Provider::GitLab, "gitlab.com", "user", "project");
+
+    let result = format_reference(Some(&repo), ReferenceKind::PullRequest, "!34");
+    assert_eq!(
+        result.as_str(),
+        "[!34](https://gitlab.com/user/project/merge_requests/34)"
+    );
+}
+
 #[test]
 fn test_format_reference_commit_github() {
     let repo = make_repo(Provider::GitHub, "github.com", "user", "project");
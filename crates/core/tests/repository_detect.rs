@@ -101,3 +101,19 @@ fn detect_repo_first_remote_when_no_origin() {
     assert_eq!(repo.owner, "acme");
     assert_eq!(repo.name, "proj");
 }
+
+#[test]
+fn detect_repo_from_nested_directory() {
+    let tmp = tempfile::tempdir().unwrap();
+    init_git(tmp.path(), "https://github.com/owner/nested.git");
+    let sub = tmp.path().join("crates/deep");
+    fs::create_dir_all(&sub).unwrap();
+    let cfg = config::load_config(LoadOptions {
+        cwd: &sub,
+        cli_overrides: None,
+    })
+    .unwrap();
+    let repo = cfg.repo.expect("repo detected from subdirectory");
+    assert_eq!(repo.owner, "owner");
+    assert_eq!(repo.name, "nested");
+}
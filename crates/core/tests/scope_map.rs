@@ -53,3 +53,80 @@ fn scope_mapping_replacement_and_removal() {
     }
     assert!(core_mapped && temp_removed);
 }
+
+/// Test regex scope mapping, capture groups and exact-match precedence.
+#[test]
+fn scope_mapping_regex_entries() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        r#"[scope_map]
+'re:^api-.*$' = "api"
+'re:^ui/(\w+)$' = "$1"
+'re:(' = "broken"
+api-legacy = "legacy"
+"#,
+    )
+    .unwrap();
+    let cfg = novalyn_core::config::load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(cfg.scope_patterns.len(), 2);
+    assert!(
+        cfg.warnings
+            .iter()
+            .any(|w| w.contains("Invalid scope_map regex '('"))
+    );
+    let commits = vec![
+        mk("feat(api-v1): one"),
+        mk("feat(api-internal): two"),
+        mk("fix(api-legacy): three"),
+        mk("fix(ui/button): four"),
+        mk("docs(apis): five"),
+    ];
+    let scopes: Vec<_> = parse_and_classify(commits.into(), &cfg)
+        .iter()
+        .map(|c| c.scope.clone())
+        .collect();
+    assert_eq!(
+        scopes,
+        vec![
+            Some("api".into()),
+            Some("api".into()),
+            Some("legacy".into()),
+            Some("button".into()),
+            Some("apis".into()),
+        ]
+    );
+}
+
+/// Each scope of a multi-scope header is mapped separately; cleared ones are dropped.
+#[test]
+fn multi_scope_entries_mapped_and_cleared_individually() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "[scope_map]\ninternal = \"\"\ncore = \"runtime\"\n",
+    )
+    .unwrap();
+    let cfg = novalyn_core::config::load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = vec![
+        mk("feat(api,internal): one"),
+        mk("fix(core, internal): two"),
+        mk("fix(internal,internal): three"),
+    ];
+    let scopes: Vec<_> = parse_and_classify(commits.into(), &cfg)
+        .iter()
+        .map(|c| c.scope.clone())
+        .collect();
+    assert_eq!(
+        scopes,
+        vec![Some("api".into()), Some("runtime".into()), None]
+    );
+}
@@ -1,7 +1,10 @@
 use assert_fs::TempDir;
+use novalyn_core::config::ZeroMajorFeat;
 use novalyn_core::config::{LoadOptions, load_config};
 use novalyn_core::git::RawCommit;
-use novalyn_core::parse::{BumpKind, infer_version, parse_and_classify};
+use novalyn_core::parse::{
+    BumpKind, InferOptions, infer_version, infer_version_with, parse_and_classify,
+};
 use semver::Version;
 
 /// Create a RawCommit with the given summary for version inference tests.
@@ -46,7 +49,11 @@ fn bump_rules_breaking_pre_1() {
     let commits = parse_and_classify(vec![mk("feat!: change")].into(), &cfg);
     let (new, kind) = infer_version(&Version::parse("0.1.0").unwrap(), &commits, None);
     assert_eq!(new, Version::parse("0.2.0").unwrap());
-    assert_eq!(kind, BumpKind::Major);
+    assert_eq!(
+        kind,
+        BumpKind::Minor,
+        "breaking change degraded to minor under pre-1.0 rule"
+    );
 }
 
 /// Test version bump rules for normal (>=1.0.0) versions.
@@ -63,3 +70,252 @@ fn bump_rules_normal() {
     assert_eq!(new, Version::parse("1.2.0").unwrap());
     assert_eq!(kind, BumpKind::Minor);
 }
+
+#[test]
+fn zero_major_feat_policy() {
+    let td = TempDir::new().unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = parse_and_classify(vec![mk("feat: add")].into(), &cfg);
+    let previous = Version::parse("0.3.2").unwrap();
+
+    let patch = InferOptions {
+        zero_major_feat: ZeroMajorFeat::Patch,
+        ..Default::default()
+    };
+    let (new, kind) = infer_version_with(&previous, &commits, None, &patch);
+    assert_eq!(new, Version::parse("0.3.3").unwrap());
+    assert_eq!(kind, BumpKind::Patch);
+
+    let minor = InferOptions {
+        zero_major_feat: ZeroMajorFeat::Minor,
+        ..Default::default()
+    };
+    let (new, kind) = infer_version_with(&previous, &commits, None, &minor);
+    assert_eq!(new, Version::parse("0.4.0").unwrap());
+    assert_eq!(kind, BumpKind::Minor);
+}
+
+#[test]
+fn disabled_type_breaking_still_bumps_major() {
+    let td = TempDir::new().unwrap();
+    std::fs::write(td.path().join("novalyn.toml"), "[types]\nrefactor=false\n").unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = parse_and_classify(
+        vec![
+            mk("fix: bug"),
+            mk("refactor: tidy"),
+            mk("refactor!: drop api"),
+        ]
+        .into(),
+        &cfg,
+    );
+    // The plain refactor is dropped; the breaking one is kept for inference
+    let summaries: Vec<_> = commits.iter().map(|c| c.raw.summary.as_str()).collect();
+    assert_eq!(summaries, ["fix: bug", "refactor!: drop api"]);
+
+    let (new, kind) = infer_version(&Version::parse("1.2.3").unwrap(), &commits, None);
+    assert_eq!(new, Version::parse("2.0.0").unwrap());
+    assert_eq!(kind, BumpKind::Major);
+}
+
+#[test]
+fn min_bump_floors_docs_only_release() {
+    let td = TempDir::new().unwrap();
+    std::fs::write(td.path().join("novalyn.toml"), "min_bump = \"minor\"\n").unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(cfg.min_bump, BumpKind::Minor);
+    let commits = parse_and_classify(vec![mk("docs: typo")].into(), &cfg);
+    let opts = InferOptions {
+        min_bump: cfg.min_bump,
+        ..Default::default()
+    };
+    let (new, kind) = infer_version_with(&Version::parse("1.2.3").unwrap(), &commits, None, &opts);
+    assert_eq!(new, Version::parse("1.3.0").unwrap());
+    assert_eq!(kind, BumpKind::Minor);
+
+    // Higher impacts are unaffected and an explicit version still wins
+    let breaking = parse_and_classify(vec![mk("docs!: rewrite")].into(), &cfg);
+    let (new, _) = infer_version_with(&Version::parse("1.2.3").unwrap(), &breaking, None, &opts);
+    assert_eq!(new, Version::parse("2.0.0").unwrap());
+    let forced = Some(Version::parse("1.2.4").unwrap());
+    let (new, _) = infer_version_with(&Version::parse("1.2.3").unwrap(), &commits, forced, &opts);
+    assert_eq!(new, Version::parse("1.2.4").unwrap());
+}
+
+#[test]
+fn prerelease_format_numbers_identifiers() {
+    let td = TempDir::new().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "prerelease_format = \"rc{{n}}\"\n",
+    )
+    .unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = parse_and_classify(vec![mk("feat: add")].into(), &cfg);
+    let opts = InferOptions {
+        prerelease: cfg.prerelease_format_on("2026-10-18"),
+        ..Default::default()
+    };
+    // Stable previous: bump, then start counting at 1
+    let (new, kind) = infer_version_with(&Version::parse("1.2.3").unwrap(), &commits, None, &opts);
+    assert_eq!(new, Version::parse("1.3.0-rc1").unwrap());
+    assert_eq!(kind, BumpKind::Minor);
+    // Matching prerelease previous: same core, next counter
+    let (new, _) = infer_version_with(&new, &commits, None, &opts);
+    assert_eq!(new, Version::parse("1.3.0-rc2").unwrap());
+    // Prerelease in another format keeps its core and restarts the counter
+    let (new, _) = infer_version_with(
+        &Version::parse("1.3.0-beta.4").unwrap(),
+        &commits,
+        None,
+        &opts,
+    );
+    assert_eq!(new, Version::parse("1.3.0-rc1").unwrap());
+}
+
+#[test]
+fn prerelease_format_date_stamped() {
+    let td = TempDir::new().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "prerelease_format = \"nightly.{{date}}.{{n}}\"\n",
+    )
+    .unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = parse_and_classify(vec![mk("fix: bug")].into(), &cfg);
+    let on = |date: &str| InferOptions {
+        prerelease: cfg.prerelease_format_on(date),
+        ..Default::default()
+    };
+    let (first, _) = infer_version_with(
+        &Version::parse("2.0.0").unwrap(),
+        &commits,
+        None,
+        &on("2026-10-17"),
+    );
+    assert_eq!(first, Version::parse("2.0.1-nightly.2026-10-17.1").unwrap());
+    let (same_day, _) = infer_version_with(&first, &commits, None, &on("2026-10-17"));
+    assert_eq!(
+        same_day,
+        Version::parse("2.0.1-nightly.2026-10-17.2").unwrap()
+    );
+    let (next_day, _) = infer_version_with(&same_day, &commits, None, &on("2026-10-18"));
+    assert_eq!(
+        next_day,
+        Version::parse("2.0.1-nightly.2026-10-18.1").unwrap()
+    );
+    assert!(next_day > same_day);
+}
+
+#[test]
+fn invalid_prerelease_format_is_ignored() {
+    let td = TempDir::new().unwrap();
+    std::fs::write(
+        td.path().join("novalyn.toml"),
+        "prerelease_format = \"rc_{{n}}\"\n",
+    )
+    .unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    assert_eq!(cfg.prerelease_format, None);
+    assert!(cfg.warnings.iter().any(|w| w.contains("prerelease_format")));
+}
+
+#[test]
+fn prerelease_channel_increments_resets_and_finalizes() {
+    let td = TempDir::new().unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let commits = parse_and_classify(vec![mk("feat: add")].into(), &cfg);
+    let channel = |name: &str| InferOptions {
+        prerelease: Some(format!("{name}.{{{{n}}}}").into()),
+        ..Default::default()
+    };
+    let infer = |prev: &str, opts: &InferOptions| {
+        infer_version_with(&Version::parse(prev).unwrap(), &commits, None, opts)
+            .0
+            .to_string()
+    };
+    assert_eq!(infer("1.1.0", &channel("rc")), "1.2.0-rc.1");
+    assert_eq!(infer("1.2.0-rc.1", &channel("rc")), "1.2.0-rc.2");
+    assert_eq!(infer("1.2.0-beta.3", &channel("rc")), "1.2.0-rc.1");
+    // Without a channel the prerelease is finalized
+    assert_eq!(infer("1.2.0-rc.2", &InferOptions::default()), "1.2.0");
+    // An explicit version still wins
+    let forced = Some(Version::parse("2.0.0").unwrap());
+    let (new, _) = infer_version_with(
+        &Version::parse("1.2.0-rc.2").unwrap(),
+        &commits,
+        forced,
+        &channel("rc"),
+    );
+    assert_eq!(new.to_string(), "2.0.0");
+}
+
+#[test]
+fn bump_beyond_prerelease_core_leaves_it() {
+    let td = TempDir::new().unwrap();
+    let cfg = load_config(LoadOptions {
+        cwd: td.path(),
+        cli_overrides: None,
+    })
+    .unwrap();
+    let channel = InferOptions {
+        prerelease: Some("rc.{{n}}".into()),
+        ..Default::default()
+    };
+    let infer = |prev: &str, summary: &str, opts: &InferOptions| {
+        let commits = parse_and_classify(vec![mk(summary)].into(), &cfg);
+        let (new, kind) = infer_version_with(&Version::parse(prev).unwrap(), &commits, None, opts);
+        (new.to_string(), kind)
+    };
+    let stable = InferOptions::default();
+    // A breaking change needs more than the rc's patch core
+    assert_eq!(
+        infer("1.2.3-rc.1", "feat!: drop api", &stable),
+        ("2.0.0".into(), BumpKind::Major)
+    );
+    assert_eq!(
+        infer("1.2.3-rc.1", "feat!: drop api", &channel),
+        ("2.0.0-rc.1".into(), BumpKind::Major)
+    );
+    assert_eq!(
+        infer("1.2.3-rc.1", "feat: add", &stable),
+        ("1.3.0".into(), BumpKind::Minor)
+    );
+    // A core that covers the bump is finalized, reporting the core's bump
+    assert_eq!(
+        infer("1.3.0-rc.2", "fix: bug", &stable),
+        ("1.3.0".into(), BumpKind::Minor)
+    );
+    assert_eq!(
+        infer("2.0.0-rc.1", "feat!: more", &channel),
+        ("2.0.0-rc.2".into(), BumpKind::Major)
+    );
+}
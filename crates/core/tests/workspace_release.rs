@@ -0,0 +1,140 @@
+use novalyn_core::git::{add_and_commit, last_tag};
+use novalyn_core::pipeline::{ReleaseOptions, run_workspace_release_async};
+use std::fs;
+use tempfile::TempDir;
+
+/// Create a two-member workspace with one commit per member.
+fn init_workspace() -> (TempDir, gix::Repository) {
+    let td = TempDir::new().unwrap();
+    let root = td.path();
+    let mut repo = novalyn_core::git::init_repo(root).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\n",
+    )
+    .unwrap();
+    fs::write(root.join("novalyn.toml"), "intro = \"# Changes\"\n").unwrap();
+    for (name, meta) in [
+        ("alpha", ""),
+        (
+            "beta",
+            "\n[package.metadata.novalyn]\nintro = \"# Beta changes\"\n",
+        ),
+    ] {
+        let dir = root.join("crates").join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n{meta}"),
+        )
+        .unwrap();
+    }
+    add_and_commit(&mut repo, "chore: scaffold workspace").unwrap();
+    fs::write(root.join("crates/alpha/lib.rs"), "// alpha").unwrap();
+    add_and_commit(&mut repo, "feat: alpha feature").unwrap();
+    fs::write(root.join("crates/beta/lib.rs"), "// beta").unwrap();
+    add_and_commit(&mut repo, "fix: beta fix").unwrap();
+    (td, repo)
+}
+
+#[tokio::test]
+async fn workspace_writes_changelog_per_member() {
+    let (td, repo) = init_workspace();
+    let outcomes = run_workspace_release_async(ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        yes: true,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    assert_eq!(outcomes.len(), 2);
+    assert!(outcomes.iter().all(|(_, o)| o.wrote));
+
+    let alpha = fs::read_to_string(td.path().join("crates/alpha/CHANGELOG.md")).unwrap();
+    assert!(alpha.starts_with("# Changes\n"));
+    assert!(alpha.contains("alpha feature"));
+    assert!(!alpha.contains("beta fix"));
+
+    let beta = fs::read_to_string(td.path().join("crates/beta/CHANGELOG.md")).unwrap();
+    assert!(beta.starts_with("# Beta changes\n"));
+    assert!(beta.contains("beta fix"));
+    assert!(!beta.contains("alpha feature"));
+
+    // Members share the tag namespace, so no release tags are created
+    assert!(last_tag(&repo).unwrap().is_none());
+    assert!(!td.path().join("CHANGELOG.md").exists());
+}
+
+/// Members inheriting `[workspace.package] version` are bumped together, once.
+#[tokio::test]
+async fn workspace_bumps_inherited_version_once() {
+    let td = TempDir::new().unwrap();
+    let root = td.path();
+    let mut repo = novalyn_core::git::init_repo(root).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    let mut lock = String::from("version = 4\n");
+    for name in ["alpha", "beta"] {
+        let dir = root.join("crates").join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion.workspace = true\n"),
+        )
+        .unwrap();
+        lock.push_str(&format!(
+            "\n[[package]]\nname = \"{name}\"\nversion = \"0.1.0\"\n"
+        ));
+    }
+    fs::write(root.join("Cargo.lock"), lock).unwrap();
+    add_and_commit(&mut repo, "chore: scaffold workspace").unwrap();
+    fs::write(root.join("crates/alpha/lib.rs"), "// alpha").unwrap();
+    add_and_commit(&mut repo, "feat!: alpha api").unwrap();
+    fs::write(root.join("crates/beta/lib.rs"), "// beta").unwrap();
+    add_and_commit(&mut repo, "fix: beta fix").unwrap();
+
+    let outcomes = run_workspace_release_async(ReleaseOptions {
+        cwd: root.into(),
+        no_authors: true,
+        yes: true,
+        update_lockfile: true,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let versions: Vec<_> = outcomes.iter().map(|(_, o)| o.version.clone()).collect();
+    assert_ne!(versions[0], versions[1]);
+    let highest = versions.iter().max().unwrap().to_string();
+
+    let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains(&format!("version = \"{highest}\"")));
+    for name in ["alpha", "beta"] {
+        let member = fs::read_to_string(root.join("crates").join(name).join("Cargo.toml")).unwrap();
+        assert!(member.contains("version.workspace = true"));
+    }
+    let lock = fs::read_to_string(root.join("Cargo.lock")).unwrap();
+    assert_eq!(
+        lock.matches(&format!("version = \"{highest}\"")).count(),
+        2,
+        "{lock}"
+    );
+}
+
+#[tokio::test]
+async fn workspace_write_requires_yes() {
+    let (td, _repo) = init_workspace();
+    let err = run_workspace_release_async(ReleaseOptions {
+        cwd: td.path().into(),
+        no_authors: true,
+        ..Default::default()
+    })
+    .await
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("--yes"), "{err}");
+    assert!(!td.path().join("crates/alpha/CHANGELOG.md").exists());
+}